@@ -0,0 +1,19 @@
+//! `cargo fuzz run scan_archive_decode` target for
+//! `commands::archive::decode_archive`, the routine `import_scan_archive`
+//! runs over an arbitrary file handed to it by a user. Mirrors the
+//! `tframe_decode` fuzz target p9 runs over its wire-format frame decoder.
+//!
+//! NOTE: this crate has no `Cargo.toml` of its own yet (the whole
+//! workspace ships as a source snapshot without one — see the top-level
+//! build notes), so there is nothing here to `cargo fuzz init` against
+//! until that lands. Until then, `archive::tests::truncated_or_oversized_length_fields_never_panic`
+//! exercises the same malformed-length-field inputs this target would.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use symview::commands::archive::decode_archive;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = decode_archive(data);
+});
@@ -0,0 +1,257 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::export::write_export;
+use crate::commands::scan::scan_path_with_walkdir_for_tests;
+use crate::commands::validate::validate_links_with_concurrency;
+use crate::types::{ExportFormat, LinkEntry, LinkStatus, LinkType};
+
+/// Which subsystem a workload exercises. `ExportLinks` also pins the output
+/// format, since `Csv`/`Json`/`Ndjson` take different code paths in
+/// `write_export`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BenchOperation {
+    ScanVolume,
+    ValidateLinks,
+    ExportLinks { format: ExportFormat },
+}
+
+impl BenchOperation {
+    fn label(&self) -> String {
+        match self {
+            BenchOperation::ScanVolume => "scan_volume".to_string(),
+            BenchOperation::ValidateLinks => "validate_links".to_string(),
+            BenchOperation::ExportLinks { format } => format!("export_links({format:?})"),
+        }
+    }
+}
+
+/// A synthetic-workload description loaded from a JSON file under
+/// `workloads/`. `link_count` symlinks are scattered across a tree
+/// `depth` directories deep, split between valid, broken and
+/// access-denied targets by the two `fraction_*` fields (the remainder is
+/// valid). `concurrency` overrides the worker cap the real command would
+/// otherwise hardcode (`VALIDATE_LINKS_CONCURRENCY` / `EXPORT_LINKS_CONCURRENCY`)
+/// so the optimal worker count can be tuned empirically instead of guessed;
+/// it's ignored by `ScanVolume`, which has no concurrency knob.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkloadSpec {
+    pub name: String,
+    pub link_count: usize,
+    pub depth: usize,
+    pub fraction_broken: f64,
+    pub fraction_access_denied: f64,
+    pub operation: BenchOperation,
+    pub concurrency: Option<usize>,
+}
+
+/// One measured run, ready to append to a JSON report so regressions in the
+/// `JoinSet` worker loops show up over time instead of only at the moment
+/// someone happens to notice the app feels slower.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchResult {
+    pub workload: String,
+    pub operation: String,
+    pub link_count: usize,
+    pub concurrency: usize,
+    pub wall_clock_ms: u128,
+    pub throughput_links_per_sec: f64,
+    pub realized_concurrency: usize,
+    pub recorded_at: String,
+}
+
+#[cfg(unix)]
+fn make_symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(windows)]
+fn make_symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::windows::fs::symlink_file(target, link)
+}
+
+/// Makes `dir` unreadable so a later `fs::metadata` hop into it fails with
+/// `PermissionDenied`, the same condition `classify_status_with_target`
+/// maps to `LinkStatus::AccessDenied`.
+#[cfg(unix)]
+fn deny_access(dir: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+    let _ = fs::set_permissions(dir, fs::Permissions::from_mode(0o000));
+}
+
+#[cfg(windows)]
+fn deny_access(dir: &Path) {
+    let _ = std::process::Command::new("icacls")
+        .arg(dir)
+        .arg("/deny")
+        .arg("Everyone:(R)")
+        .output();
+}
+
+/// Undoes `deny_access` on every tracked directory before teardown, since a
+/// leftover `0o000`/denied ACL would otherwise make the final
+/// `fs::remove_dir_all` fail partway through and leak the temp tree.
+fn restore_access(denied_dirs: &[PathBuf]) {
+    for dir in denied_dirs {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let _ = fs::set_permissions(dir, fs::Permissions::from_mode(0o755));
+        }
+        #[cfg(windows)]
+        {
+            let _ = std::process::Command::new("icacls")
+                .arg(dir)
+                .arg("/reset")
+                .output();
+        }
+    }
+}
+
+/// Builds a synthetic link tree under a fresh temp directory: `depth` nested
+/// subdirectories round-robin `link_count` symlinks between valid, broken
+/// and access-denied targets per the workload's fractions. Returns the root
+/// directory, the `LinkEntry` list for operations that take entries
+/// directly instead of scanning for them, and the access-denied directories
+/// so the caller can `restore_access` them before teardown.
+fn materialize_workload(spec: &WorkloadSpec) -> Result<(PathBuf, Vec<LinkEntry>, Vec<PathBuf>), String> {
+    let root = std::env::temp_dir().join(format!("symview-bench-{}-{}", spec.name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+
+    let depth = spec.depth.max(1);
+    let mut level_dirs = Vec::with_capacity(depth);
+    let mut current = root.clone();
+    for level in 0..depth {
+        current = current.join(format!("level-{level}"));
+        fs::create_dir_all(&current).map_err(|e| format!("Failed to create bench tree dir: {e}"))?;
+        level_dirs.push(current.clone());
+    }
+
+    let valid_target = root.join("valid-target.txt");
+    fs::write(&valid_target, b"symview-bench").map_err(|e| format!("Failed to write bench target: {e}"))?;
+
+    let broken_count = ((spec.link_count as f64) * spec.fraction_broken).round() as usize;
+    let denied_count = ((spec.link_count as f64) * spec.fraction_access_denied).round() as usize;
+
+    let mut entries = Vec::with_capacity(spec.link_count);
+    let mut denied_dirs = Vec::new();
+
+    for index in 0..spec.link_count {
+        let dir = &level_dirs[index % depth];
+        let link_path = dir.join(format!("link-{index}"));
+
+        let target = if index < broken_count {
+            dir.join(format!("missing-target-{index}"))
+        } else if index < broken_count + denied_count {
+            let denied_dir = dir.join(format!("denied-{index}"));
+            fs::create_dir_all(&denied_dir).map_err(|e| format!("Failed to create denied dir: {e}"))?;
+            let denied_target = denied_dir.join("target.txt");
+            fs::write(&denied_target, b"secret").map_err(|e| format!("Failed to write denied target: {e}"))?;
+            deny_access(&denied_dir);
+            denied_dirs.push(denied_dir);
+            denied_target
+        } else {
+            valid_target.clone()
+        };
+
+        make_symlink(&target, &link_path).map_err(|e| format!("Failed to create bench symlink: {e}"))?;
+
+        entries.push(LinkEntry {
+            path: link_path.to_string_lossy().to_string(),
+            target: target.to_string_lossy().to_string(),
+            link_type: LinkType::Symlink,
+            status: LinkStatus::Ok,
+            hardlink_siblings: Vec::new(),
+        });
+    }
+
+    Ok((root, entries, denied_dirs))
+}
+
+/// Runs one workload to completion and reports wall-clock time, throughput,
+/// and realized concurrency. "Realized concurrency" is the worker count the
+/// `JoinSet` loop actually sustained: the cap itself once the backlog
+/// outnumbers it, or the link count when the workload is too small to fill
+/// every slot.
+pub async fn run_benchmark(spec: &WorkloadSpec, output_dir: &Path) -> Result<BenchResult, String> {
+    let (root, entries, denied_dirs) = materialize_workload(spec)?;
+    let link_count = entries.len();
+
+    let configured_concurrency = match &spec.operation {
+        BenchOperation::ScanVolume => 1,
+        BenchOperation::ValidateLinks => spec.concurrency.unwrap_or(crate::commands::validate::VALIDATE_LINKS_CONCURRENCY),
+        BenchOperation::ExportLinks { .. } => spec.concurrency.unwrap_or(crate::commands::export::EXPORT_LINKS_CONCURRENCY),
+    };
+    let realized_concurrency = configured_concurrency.max(1).min(link_count.max(1));
+
+    let started = Instant::now();
+
+    let run_result: Result<(), String> = match &spec.operation {
+        BenchOperation::ScanVolume => {
+            scan_path_with_walkdir_for_tests(root.to_str().ok_or("bench root path is not valid UTF-8")?).map(|_| ())
+        }
+        BenchOperation::ValidateLinks => {
+            validate_links_with_concurrency(entries, realized_concurrency).await;
+            Ok(())
+        }
+        BenchOperation::ExportLinks { format } => {
+            let out_path = output_dir.join(format!("{}-export.out", spec.name));
+            write_export(entries, format.clone(), out_path.to_str().unwrap_or("symview-bench-export.out"), realized_concurrency, |_, _| {}).await
+        }
+    };
+
+    let wall_clock = started.elapsed();
+    restore_access(&denied_dirs);
+    let _ = fs::remove_dir_all(&root);
+    run_result?;
+
+    let wall_clock_ms = wall_clock.as_millis();
+    let throughput_links_per_sec = if wall_clock.as_secs_f64() > 0.0 {
+        link_count as f64 / wall_clock.as_secs_f64()
+    } else {
+        link_count as f64
+    };
+
+    Ok(BenchResult {
+        workload: spec.name.clone(),
+        operation: spec.operation.label(),
+        link_count,
+        concurrency: configured_concurrency,
+        wall_clock_ms,
+        throughput_links_per_sec,
+        realized_concurrency,
+        recorded_at: Utc::now().to_rfc3339(),
+    })
+}
+
+/// Loads a workload JSON file, runs it, and appends the result to
+/// `report_path` (a JSON array, created if it doesn't exist yet) so a
+/// history of runs accumulates in one place.
+pub async fn run_workload_file(workload_path: &Path, report_path: &Path) -> Result<BenchResult, String> {
+    let workload_json = fs::read_to_string(workload_path)
+        .map_err(|e| format!("Failed to read workload file {}: {e}", workload_path.display()))?;
+    let spec: WorkloadSpec = serde_json::from_str(&workload_json)
+        .map_err(|e| format!("Failed to parse workload file {}: {e}", workload_path.display()))?;
+
+    let output_dir = std::env::temp_dir();
+    let result = run_benchmark(&spec, &output_dir).await?;
+
+    let mut report: Vec<BenchResult> = if let Ok(existing) = fs::read_to_string(report_path) {
+        serde_json::from_str(&existing).unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    report.push(result.clone());
+
+    let report_json = serde_json::to_vec_pretty(&report)
+        .map_err(|e| format!("Failed to serialize bench report: {e}"))?;
+    fs::write(report_path, report_json).map_err(|e| format!("Failed to write bench report: {e}"))?;
+
+    Ok(result)
+}
@@ -0,0 +1,324 @@
+//! A portable binary archive format for `ScanResult`, so a large scan can
+//! be saved to disk and reopened later — or handed to other tooling —
+//! without depending on serde or the Tauri event bus. The shape mirrors
+//! what a `#[derive(WireFormat)]` macro (p9's `wire_format_derive`) would
+//! generate: a one-byte discriminant tag per enum, little-endian
+//! fixed-width integers, and length-prefixed UTF-8 strings. This crate
+//! carries no proc-macro/build-script infrastructure, so `WireEncode`/
+//! `WireDecode` are implemented by hand per type instead of derived, but
+//! every impl below is the mechanical encoding a derive macro would emit.
+//!
+//! `WireDecode::wire_decode` is the only thing that ever sees untrusted
+//! bytes (a `.svscan` file handed back in by a user), so every length
+//! field is bounds-checked against the remaining buffer before use —
+//! there is no path from a malformed length to an out-of-bounds read.
+
+use std::path::Path;
+
+use crate::commands::export::write_atomic;
+use crate::types::{LinkEntry, LinkStatus, LinkType, ScanMode, ScanResult};
+
+const ARCHIVE_MAGIC: [u8; 4] = *b"SVSA";
+const ARCHIVE_VERSION: u32 = 1;
+
+pub(crate) trait WireEncode {
+    fn wire_encode(&self, buffer: &mut Vec<u8>);
+}
+
+pub(crate) trait WireDecode: Sized {
+    fn wire_decode(data: &[u8], offset: &mut usize) -> Option<Self>;
+}
+
+impl WireEncode for u8 {
+    fn wire_encode(&self, buffer: &mut Vec<u8>) {
+        buffer.push(*self);
+    }
+}
+
+impl WireDecode for u8 {
+    fn wire_decode(data: &[u8], offset: &mut usize) -> Option<Self> {
+        let value = *data.get(*offset)?;
+        *offset += 1;
+        Some(value)
+    }
+}
+
+impl WireEncode for u32 {
+    fn wire_encode(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.to_le_bytes());
+    }
+}
+
+impl WireDecode for u32 {
+    fn wire_decode(data: &[u8], offset: &mut usize) -> Option<Self> {
+        let end = offset.checked_add(4)?;
+        let bytes: [u8; 4] = data.get(*offset..end)?.try_into().ok()?;
+        *offset = end;
+        Some(u32::from_le_bytes(bytes))
+    }
+}
+
+impl WireEncode for String {
+    fn wire_encode(&self, buffer: &mut Vec<u8>) {
+        let bytes = self.as_bytes();
+        (bytes.len() as u32).wire_encode(buffer);
+        buffer.extend_from_slice(bytes);
+    }
+}
+
+impl WireDecode for String {
+    fn wire_decode(data: &[u8], offset: &mut usize) -> Option<Self> {
+        let len = u32::wire_decode(data, offset)? as usize;
+        let end = offset.checked_add(len)?;
+        let bytes = data.get(*offset..end)?;
+        let value = String::from_utf8(bytes.to_vec()).ok()?;
+        *offset = end;
+        Some(value)
+    }
+}
+
+impl<T: WireEncode> WireEncode for Vec<T> {
+    fn wire_encode(&self, buffer: &mut Vec<u8>) {
+        (self.len() as u32).wire_encode(buffer);
+        for item in self {
+            item.wire_encode(buffer);
+        }
+    }
+}
+
+impl<T: WireDecode> WireDecode for Vec<T> {
+    fn wire_decode(data: &[u8], offset: &mut usize) -> Option<Self> {
+        let count = u32::wire_decode(data, offset)? as usize;
+        let mut items = Vec::with_capacity(count.min(data.len()));
+        for _ in 0..count {
+            items.push(T::wire_decode(data, offset)?);
+        }
+        Some(items)
+    }
+}
+
+impl WireEncode for LinkType {
+    fn wire_encode(&self, buffer: &mut Vec<u8>) {
+        let tag: u8 = match self {
+            LinkType::Symlink => 0,
+            LinkType::Junction => 1,
+            LinkType::Hardlink => 2,
+        };
+        tag.wire_encode(buffer);
+    }
+}
+
+impl WireDecode for LinkType {
+    fn wire_decode(data: &[u8], offset: &mut usize) -> Option<Self> {
+        Some(match u8::wire_decode(data, offset)? {
+            1 => LinkType::Junction,
+            2 => LinkType::Hardlink,
+            _ => LinkType::Symlink,
+        })
+    }
+}
+
+impl WireEncode for LinkStatus {
+    fn wire_encode(&self, buffer: &mut Vec<u8>) {
+        match self {
+            LinkStatus::Ok => 0_u8.wire_encode(buffer),
+            LinkStatus::Broken(reason) => {
+                1_u8.wire_encode(buffer);
+                reason.wire_encode(buffer);
+            }
+            LinkStatus::AccessDenied => 2_u8.wire_encode(buffer),
+            LinkStatus::Recursive => 3_u8.wire_encode(buffer),
+            LinkStatus::PolicyViolation(reason) => {
+                4_u8.wire_encode(buffer);
+                reason.wire_encode(buffer);
+            }
+            LinkStatus::Cyclic => 5_u8.wire_encode(buffer),
+        }
+    }
+}
+
+impl WireDecode for LinkStatus {
+    fn wire_decode(data: &[u8], offset: &mut usize) -> Option<Self> {
+        Some(match u8::wire_decode(data, offset)? {
+            1 => LinkStatus::Broken(String::wire_decode(data, offset)?),
+            2 => LinkStatus::AccessDenied,
+            3 => LinkStatus::Recursive,
+            4 => LinkStatus::PolicyViolation(String::wire_decode(data, offset)?),
+            5 => LinkStatus::Cyclic,
+            _ => LinkStatus::Ok,
+        })
+    }
+}
+
+impl WireEncode for LinkEntry {
+    fn wire_encode(&self, buffer: &mut Vec<u8>) {
+        self.path.wire_encode(buffer);
+        self.target.wire_encode(buffer);
+        self.link_type.wire_encode(buffer);
+        self.status.wire_encode(buffer);
+        self.hardlink_siblings.wire_encode(buffer);
+    }
+}
+
+impl WireDecode for LinkEntry {
+    fn wire_decode(data: &[u8], offset: &mut usize) -> Option<Self> {
+        Some(LinkEntry {
+            path: String::wire_decode(data, offset)?,
+            target: String::wire_decode(data, offset)?,
+            link_type: LinkType::wire_decode(data, offset)?,
+            status: LinkStatus::wire_decode(data, offset)?,
+            hardlink_siblings: Vec::wire_decode(data, offset)?,
+        })
+    }
+}
+
+impl WireEncode for ScanMode {
+    fn wire_encode(&self, buffer: &mut Vec<u8>) {
+        let tag: u8 = match self {
+            ScanMode::UsnJournal => 0,
+            ScanMode::WalkdirFallback => 1,
+        };
+        tag.wire_encode(buffer);
+    }
+}
+
+impl WireDecode for ScanMode {
+    fn wire_decode(data: &[u8], offset: &mut usize) -> Option<Self> {
+        Some(match u8::wire_decode(data, offset)? {
+            1 => ScanMode::WalkdirFallback,
+            _ => ScanMode::UsnJournal,
+        })
+    }
+}
+
+impl WireEncode for ScanResult {
+    fn wire_encode(&self, buffer: &mut Vec<u8>) {
+        self.entries.wire_encode(buffer);
+        self.mode.wire_encode(buffer);
+    }
+}
+
+impl WireDecode for ScanResult {
+    fn wire_decode(data: &[u8], offset: &mut usize) -> Option<Self> {
+        Some(ScanResult {
+            entries: Vec::<LinkEntry>::wire_decode(data, offset)?,
+            mode: ScanMode::wire_decode(data, offset)?,
+        })
+    }
+}
+
+fn encode_archive(result: &ScanResult) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    buffer.extend_from_slice(&ARCHIVE_MAGIC);
+    buffer.extend_from_slice(&ARCHIVE_VERSION.to_le_bytes());
+    result.wire_encode(&mut buffer);
+    buffer
+}
+
+/// Entry point for the fuzz target: parses a raw frame exactly the way
+/// `import_scan_archive` does, so a malformed length field can only ever
+/// yield `None`, never an out-of-bounds read.
+pub fn decode_archive(data: &[u8]) -> Option<ScanResult> {
+    if data.len() < 8 || data[0..4] != ARCHIVE_MAGIC {
+        return None;
+    }
+
+    let mut offset = 4;
+    let version = u32::wire_decode(data, &mut offset)?;
+    if version != ARCHIVE_VERSION {
+        return None;
+    }
+
+    ScanResult::wire_decode(data, &mut offset)
+}
+
+/// Writes `result` to `path` in the portable binary archive format. Named
+/// `*_archive` rather than `export_scan` to avoid colliding with the
+/// existing CSV/JSON/NDJSON `export_scan` command in
+/// `crate::commands::export`, which takes a different argument shape.
+#[tauri::command]
+pub fn export_scan_archive(result: ScanResult, path: String) -> Result<(), String> {
+    write_atomic(Path::new(&path), &encode_archive(&result))
+}
+
+#[tauri::command]
+pub fn import_scan_archive(path: String) -> Result<ScanResult, String> {
+    let bytes = std::fs::read(&path).map_err(|e| format!("Failed to read scan archive {path}: {e}"))?;
+    decode_archive(&bytes).ok_or_else(|| format!("Scan archive {path} is corrupt or has an unsupported version"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_result() -> ScanResult {
+        ScanResult {
+            entries: vec![
+                LinkEntry {
+                    path: "C:\\links\\a".to_string(),
+                    target: "C:\\targets\\a".to_string(),
+                    link_type: LinkType::Symlink,
+                    status: LinkStatus::Ok,
+                    hardlink_siblings: Vec::new(),
+                },
+                LinkEntry {
+                    path: "C:\\links\\b".to_string(),
+                    target: String::new(),
+                    link_type: LinkType::Junction,
+                    status: LinkStatus::Broken("target does not exist".to_string()),
+                    hardlink_siblings: Vec::new(),
+                },
+                LinkEntry {
+                    path: "C:\\links\\c".to_string(),
+                    target: "C:\\targets\\c".to_string(),
+                    link_type: LinkType::Hardlink,
+                    status: LinkStatus::PolicyViolation("blocked by policy".to_string()),
+                    hardlink_siblings: vec!["C:\\links\\c2".to_string()],
+                },
+            ],
+            mode: ScanMode::UsnJournal,
+        }
+    }
+
+    #[test]
+    fn round_trips_through_the_wire_format() {
+        let original = sample_result();
+        let bytes = encode_archive(&original);
+        let decoded = decode_archive(&bytes).expect("valid archive should decode");
+
+        assert_eq!(decoded.entries.len(), original.entries.len());
+        assert_eq!(decoded.entries[0].path, original.entries[0].path);
+        assert_eq!(decoded.entries[1].target, original.entries[1].target);
+        assert!(matches!(decoded.entries[1].status, LinkStatus::Broken(ref reason) if reason == "target does not exist"));
+        assert!(matches!(decoded.mode, ScanMode::UsnJournal));
+    }
+
+    #[test]
+    fn rejects_bad_magic_and_version() {
+        assert!(decode_archive(b"NOPE").is_none());
+
+        let mut bytes = encode_archive(&sample_result());
+        bytes[4] = 0xFF;
+        assert!(decode_archive(&bytes).is_none());
+    }
+
+    #[test]
+    fn truncated_or_oversized_length_fields_never_panic() {
+        let mut bytes = encode_archive(&sample_result());
+
+        // Truncate mid-frame: every length-prefixed read must bail out
+        // with `None` instead of indexing past the end of the slice.
+        for cut in (0..bytes.len()).step_by(7) {
+            assert!(decode_archive(&bytes[..cut]).is_none() || cut == bytes.len());
+        }
+
+        // Corrupt the entry-count prefix to an absurd value; `Vec::wire_decode`
+        // must stop at the real buffer boundary instead of reading garbage.
+        let entries_len_offset = 8;
+        bytes[entries_len_offset] = 0xFF;
+        bytes[entries_len_offset + 1] = 0xFF;
+        bytes[entries_len_offset + 2] = 0xFF;
+        bytes[entries_len_offset + 3] = 0x7F;
+        assert!(decode_archive(&bytes).is_none());
+    }
+}
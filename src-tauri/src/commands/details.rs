@@ -1,12 +1,24 @@
 use std::fs;
+use std::os::windows::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::time::SystemTime;
 
 use chrono::{DateTime, Utc};
+use windows_sys::Win32::Foundation::{GetLastError, HLOCAL, LocalFree, PSID};
+use windows_sys::Win32::Security::Authorization::{GetNamedSecurityInfoW, SE_FILE_OBJECT};
+use windows_sys::Win32::Security::{LookupAccountSidW, OWNER_SECURITY_INFORMATION, PSECURITY_DESCRIPTOR, SID_NAME_USE};
 
+use crate::commands::scan::get_hardlink_info;
 use crate::types::{LinkDetails, LinkStatus, LinkType, ObjectType};
 
+fn to_wide_null(value: &str) -> Vec<u16> {
+    std::ffi::OsStr::new(value)
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect()
+}
+
 fn iso_time(value: Result<SystemTime, std::io::Error>) -> String {
     value
         .ok()
@@ -14,6 +26,30 @@ fn iso_time(value: Result<SystemTime, std::io::Error>) -> String {
         .unwrap_or_default()
 }
 
+/// Fallback used when `GetFileInformationByHandle` fails (e.g. the path is on
+/// a filesystem that doesn't expose a usable link count through that call).
+fn detect_hardlink_via_fsutil(path: &str) -> LinkType {
+    let output = Command::new("fsutil")
+        .args(["hardlink", "list", path])
+        .output();
+
+    if let Ok(value) = output {
+        if value.status.success() {
+            let count = String::from_utf8_lossy(&value.stdout)
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .count();
+
+            if count > 1 {
+                return LinkType::Hardlink;
+            }
+        }
+    }
+
+    LinkType::Symlink
+}
+
 fn detect_link_type(path: &str) -> LinkType {
     match fs::symlink_metadata(path) {
         Ok(metadata) => {
@@ -24,32 +60,18 @@ fn detect_link_type(path: &str) -> LinkType {
                     LinkType::Symlink
                 }
             } else {
-                let output = Command::new("fsutil")
-                    .args(["hardlink", "list", path])
-                    .output();
-
-                if let Ok(value) = output {
-                    if value.status.success() {
-                        let count = String::from_utf8_lossy(&value.stdout)
-                            .lines()
-                            .map(str::trim)
-                            .filter(|line| !line.is_empty())
-                            .count();
-
-                        if count > 1 {
-                            return LinkType::Hardlink;
-                        }
-                    }
+                match get_hardlink_info(path) {
+                    Ok((_, _, links_count)) if links_count > 1 => LinkType::Hardlink,
+                    Ok(_) => LinkType::Symlink,
+                    Err(_) => detect_hardlink_via_fsutil(path),
                 }
-
-                LinkType::Symlink
             }
         }
         Err(_) => LinkType::Symlink,
     }
 }
 
-fn resolve_target(path: &str, stored_target: &str) -> String {
+pub(crate) fn resolve_target(path: &str, stored_target: &str) -> String {
     let stored_path = PathBuf::from(stored_target);
     let absolute = if stored_path.is_absolute() {
         stored_path
@@ -81,7 +103,9 @@ fn normalize_display_path(path: &Path) -> String {
     value
 }
 
-fn resolve_owner(path: &str) -> String {
+/// Fallback used when the in-process SID lookup fails (e.g. a domain
+/// controller is unreachable and the SID can't be resolved to a name).
+fn resolve_owner_via_powershell(path: &str) -> String {
     let script = "(Get-Acl -LiteralPath $args[0]).Owner";
 
     let output = Command::new("powershell")
@@ -95,6 +119,73 @@ fn resolve_owner(path: &str) -> String {
     }
 }
 
+fn resolve_owner_native(path: &str) -> Result<String, String> {
+    let wide = to_wide_null(path);
+    let mut owner_sid: PSID = std::ptr::null_mut();
+    let mut descriptor: PSECURITY_DESCRIPTOR = std::ptr::null_mut();
+
+    let status = unsafe {
+        GetNamedSecurityInfoW(
+            wide.as_ptr(),
+            SE_FILE_OBJECT,
+            OWNER_SECURITY_INFORMATION,
+            &mut owner_sid,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            &mut descriptor,
+        )
+    };
+
+    if status != 0 {
+        return Err(format!("GetNamedSecurityInfoW failed for {path}: {status}"));
+    }
+
+    let mut name = vec![0_u16; 256];
+    let mut name_len = name.len() as u32;
+    let mut domain = vec![0_u16; 256];
+    let mut domain_len = domain.len() as u32;
+    let mut sid_use: SID_NAME_USE = 0;
+
+    let ok = unsafe {
+        LookupAccountSidW(
+            std::ptr::null(),
+            owner_sid,
+            name.as_mut_ptr(),
+            &mut name_len,
+            domain.as_mut_ptr(),
+            &mut domain_len,
+            &mut sid_use,
+        )
+    };
+
+    let lookup_error = if ok == 0 { Some(unsafe { GetLastError() }) } else { None };
+
+    unsafe {
+        LocalFree(descriptor as HLOCAL);
+    }
+
+    if let Some(error) = lookup_error {
+        return Err(format!("LookupAccountSidW failed for {path}: {error}"));
+    }
+
+    let account = String::from_utf16_lossy(&name[..name_len as usize]);
+    let domain_name = String::from_utf16_lossy(&domain[..domain_len as usize]);
+
+    if domain_name.is_empty() {
+        Ok(account)
+    } else {
+        Ok(format!("{domain_name}\\{account}"))
+    }
+}
+
+fn resolve_owner(path: &str) -> String {
+    match resolve_owner_native(path) {
+        Ok(owner) if !owner.is_empty() => owner,
+        _ => resolve_owner_via_powershell(path),
+    }
+}
+
 fn map_attributes(path: &str) -> Vec<String> {
     let mut result = Vec::new();
 
@@ -131,15 +222,78 @@ fn map_attributes(path: &str) -> Vec<String> {
     result
 }
 
-fn classify_status(target_real: &str) -> LinkStatus {
-    match fs::metadata(target_real) {
-        Ok(_) => LinkStatus::Ok,
-        Err(error) => match error.kind() {
-            std::io::ErrorKind::PermissionDenied => LinkStatus::AccessDenied,
-            std::io::ErrorKind::NotFound => LinkStatus::Broken("target does not exist".to_string()),
-            _ => LinkStatus::Broken(error.to_string()),
-        },
+const MAX_LINK_HOPS: usize = 40;
+
+/// Lexical (non-OS) normalization used only to de-duplicate chain hops in the
+/// cycle-detection visited set. Deliberately avoids `Path::canonicalize`,
+/// which would itself recurse through the very reparse chain we're probing.
+fn lexically_normalize(path: &Path) -> String {
+    use std::path::Component;
+
+    let mut stack: Vec<String> = Vec::new();
+
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                stack.pop();
+            }
+            Component::CurDir => {}
+            Component::Normal(part) => stack.push(part.to_string_lossy().to_string()),
+            Component::RootDir | Component::Prefix(_) => {
+                stack.push(component.as_os_str().to_string_lossy().to_string())
+            }
+        }
+    }
+
+    stack.join("\\").to_lowercase()
+}
+
+fn resolve_hop(path: &Path, target: &str) -> PathBuf {
+    let target_path = PathBuf::from(target);
+
+    if target_path.is_absolute() {
+        target_path
+    } else {
+        path.parent().unwrap_or_else(|| Path::new("")).join(target_path)
+    }
+}
+
+pub(crate) fn classify_status(start_path: &str) -> LinkStatus {
+    classify_status_with_target(start_path).0
+}
+
+/// Same hop-walking logic as `classify_status`, but also returns the
+/// lexical path of the final hop so callers (like the Lua policy engine)
+/// can inspect where a chain actually resolves to without re-walking it.
+pub(crate) fn classify_status_with_target(start_path: &str) -> (LinkStatus, String) {
+    let mut current = PathBuf::from(start_path);
+    let mut visited: std::collections::HashSet<String> = std::collections::HashSet::new();
+
+    for _ in 0..MAX_LINK_HOPS {
+        if !visited.insert(lexically_normalize(&current)) {
+            return (LinkStatus::Cyclic, current.to_string_lossy().to_string());
+        }
+
+        match fs::read_link(&current) {
+            Ok(target) => {
+                let target_text = target.to_string_lossy().to_string();
+                current = resolve_hop(&current, &target_text);
+            }
+            Err(_) => {
+                let status = match fs::metadata(&current) {
+                    Ok(_) => LinkStatus::Ok,
+                    Err(error) => match error.kind() {
+                        std::io::ErrorKind::PermissionDenied => LinkStatus::AccessDenied,
+                        std::io::ErrorKind::NotFound => LinkStatus::Broken("target does not exist".to_string()),
+                        _ => LinkStatus::Broken(error.to_string()),
+                    },
+                };
+                return (status, current.to_string_lossy().to_string());
+            }
+        }
     }
+
+    (LinkStatus::Recursive, current.to_string_lossy().to_string())
 }
 
 #[tauri::command]
@@ -165,6 +319,31 @@ pub fn get_link_details(path: String) -> Result<LinkDetails, String> {
         modified_at: iso_time(metadata.modified()),
         owner: resolve_owner(&path),
         attributes: map_attributes(&path),
-        status: classify_status(&target_real),
+        status: classify_status(&path),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lexical_normalize_collapses_parent_dir_and_case() {
+        assert_eq!(
+            lexically_normalize(Path::new("C:\\Data\\sub\\..\\Item.txt")),
+            lexically_normalize(Path::new("c:\\data\\item.txt"))
+        );
+    }
+
+    #[test]
+    fn resolve_hop_joins_relative_targets_against_parent() {
+        let resolved = resolve_hop(Path::new("C:\\root\\link.txt"), "target.txt");
+        assert_eq!(resolved, PathBuf::from("C:\\root\\target.txt"));
+    }
+
+    #[test]
+    fn resolve_hop_keeps_absolute_targets_as_is() {
+        let resolved = resolve_hop(Path::new("C:\\root\\link.txt"), "D:\\other\\target.txt");
+        assert_eq!(resolved, PathBuf::from("D:\\other\\target.txt"));
+    }
+}
@@ -1,30 +1,191 @@
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{BufWriter, Write};
+use std::path::Path;
 
 use tauri::{AppHandle, Emitter};
 use tokio::task::JoinSet;
 
 use crate::commands::details::get_link_details;
-use crate::types::{ExportFormat, LinkEntry};
+use crate::types::{ExportFormat, LinkDetails, LinkEntry};
+
+pub(crate) const EXPORT_LINKS_CONCURRENCY: usize = 8;
+
+/// Writes `content` to a temp file in the same directory as `destination`,
+/// flushes it, then renames over the destination in a single call so a
+/// crash mid-write never leaves a half-written file behind (rename is
+/// atomic on NTFS).
+pub(crate) fn write_atomic(destination: &Path, content: &[u8]) -> Result<(), String> {
+    let dir = destination.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or_else(|| Path::new("."));
+    let file_name = destination
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("symview-export");
+    let temp_path = dir.join(format!(".{file_name}.tmp"));
+
+    {
+        let mut file = File::create(&temp_path)
+            .map_err(|e| format!("Failed to create temp export file: {e}"))?;
+        file.write_all(content)
+            .map_err(|e| format!("Failed to write temp export file: {e}"))?;
+        file.sync_all()
+            .map_err(|e| format!("Failed to sync temp export file: {e}"))?;
+    }
+
+    if let Err(error) = std::fs::rename(&temp_path, destination) {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(format!("Failed to finalize export file: {error}"));
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn export_scan(entries: Vec<LinkEntry>, path: String, format: ExportFormat) -> Result<(), String> {
+    let destination = std::path::PathBuf::from(&path);
+    let details: Vec<_> = entries
+        .into_iter()
+        .filter_map(|entry| get_link_details(entry.path).ok())
+        .collect();
+
+    let content = match format {
+        ExportFormat::Json => serde_json::to_vec_pretty(&details)
+            .map_err(|e| format!("Failed to serialize scan results as JSON: {e}"))?,
+        ExportFormat::Ndjson => {
+            let mut buffer = Vec::new();
+            for item in &details {
+                serde_json::to_writer(&mut buffer, item)
+                    .map_err(|e| format!("Failed to serialize scan record as NDJSON: {e}"))?;
+                buffer.push(b'\n');
+            }
+            buffer
+        }
+        ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+
+            writer
+                .write_record([
+                    "link_path",
+                    "target_stored",
+                    "target_real",
+                    "link_type",
+                    "status",
+                    "object_type",
+                    "created_at",
+                    "owner",
+                    "attributes",
+                ])
+                .map_err(|e| format!("Failed to write CSV header: {e}"))?;
+
+            for item in &details {
+                writer
+                    .write_record([
+                        item.path.clone(),
+                        item.target_stored.clone(),
+                        item.target_real.clone(),
+                        format!("{:?}", item.link_type),
+                        status_to_string(&item.status),
+                        format!("{:?}", item.object_type),
+                        item.created_at.clone(),
+                        item.owner.clone(),
+                        item.attributes.join(";"),
+                    ])
+                    .map_err(|e| format!("Failed to write CSV row: {e}"))?;
+            }
+
+            writer
+                .into_inner()
+                .map_err(|e| format!("Failed to flush scan CSV writer: {e}"))?
+        }
+    };
+
+    write_atomic(&destination, &content)
+}
 
 fn status_to_string(status: &crate::types::LinkStatus) -> String {
     match status {
         crate::types::LinkStatus::Ok => "Ok".to_string(),
         crate::types::LinkStatus::AccessDenied => "AccessDenied".to_string(),
+        crate::types::LinkStatus::Recursive => "Recursive".to_string(),
+        crate::types::LinkStatus::Cyclic => "Cyclic".to_string(),
         crate::types::LinkStatus::Broken(reason) => format!("Broken({reason})"),
+        crate::types::LinkStatus::PolicyViolation(reason) => format!("PolicyViolation({reason})"),
     }
 }
 
-#[tauri::command]
-pub async fn export_links(
-    app: AppHandle,
+/// Runs `get_link_details` for every entry across `concurrency` concurrent
+/// workers, tagging each task with its original index so out-of-order
+/// completions can be buffered in a `BTreeMap` and flushed to `on_record` in
+/// input order as soon as the contiguous prefix is ready. Calls
+/// `on_progress(processed, total)` as each record is flushed; callers that
+/// care about UI feedback wire that into an `AppHandle::emit`, callers that
+/// don't (e.g. the bench harness) pass a no-op.
+///
+/// `concurrency` is caller-supplied rather than hardcoded so the bench
+/// harness (see `crate::bench`) can sweep worker counts to find the
+/// empirically optimal value instead of trusting `EXPORT_LINKS_CONCURRENCY`
+/// forever.
+pub(crate) async fn export_ordered<F, P>(
+    entries: Vec<LinkEntry>,
+    concurrency: usize,
+    mut on_record: F,
+    mut on_progress: P,
+) -> Result<(), String>
+where
+    F: FnMut(LinkDetails) -> Result<(), String>,
+    P: FnMut(u64, u64),
+{
+    let total = entries.len() as u64;
+    let mut pending: Vec<(usize, LinkEntry)> = entries.into_iter().enumerate().rev().collect();
+    let mut set = JoinSet::new();
+    let mut ready: BTreeMap<usize, LinkDetails> = BTreeMap::new();
+    let mut next_index = 0_usize;
+    let mut processed = 0_u64;
+
+    loop {
+        while set.len() < concurrency.max(1) {
+            if let Some((index, entry)) = pending.pop() {
+                set.spawn(async move { (index, get_link_details(entry.path)) });
+            } else {
+                break;
+            }
+        }
+
+        if set.is_empty() {
+            break;
+        }
+
+        if let Some(result) = set.join_next().await {
+            let (index, details) = result.map_err(|e| format!("Export worker join error: {e}"))?;
+            ready.insert(index, details?);
+
+            while let Some(details) = ready.remove(&next_index) {
+                on_record(details)?;
+                next_index += 1;
+                processed += 1;
+
+                on_progress(processed, total);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes `entries` to `path` in `format`, resolving link details across
+/// `concurrency` workers via [`export_ordered`]. Shared by the `export_links`
+/// command (which reports progress to the UI) and the bench harness (which
+/// doesn't need an `AppHandle` and just wants a timed, repeatable run).
+pub(crate) async fn write_export(
     entries: Vec<LinkEntry>,
     format: ExportFormat,
-    path: String,
+    path: &str,
+    concurrency: usize,
+    on_progress: impl FnMut(u64, u64),
 ) -> Result<(), String> {
     match format {
         ExportFormat::Csv => {
-            let file = File::create(&path).map_err(|e| format!("Failed to create CSV file: {e}"))?;
+            let file = File::create(path).map_err(|e| format!("Failed to create CSV file: {e}"))?;
             let mut writer = csv::Writer::from_writer(BufWriter::new(file));
 
             writer
@@ -40,58 +201,44 @@ pub async fn export_links(
                 ])
                 .map_err(|e| format!("Failed to write CSV header: {e}"))?;
 
-            for entry in entries {
-                let details = get_link_details(entry.path.clone())?;
-
-                writer
-                    .write_record([
-                        details.path,
-                        details.target_stored,
-                        details.target_real,
-                        format!("{:?}", details.link_type),
-                        status_to_string(&details.status),
-                        format!("{:?}", details.object_type),
-                        details.created_at,
-                        details.owner,
-                    ])
-                    .map_err(|e| format!("Failed to write CSV row: {e}"))?;
-            }
+            export_ordered(
+                entries,
+                concurrency,
+                |details| {
+                    writer
+                        .write_record([
+                            details.path,
+                            details.target_stored,
+                            details.target_real,
+                            format!("{:?}", details.link_type),
+                            status_to_string(&details.status),
+                            format!("{:?}", details.object_type),
+                            details.created_at,
+                            details.owner,
+                        ])
+                        .map_err(|e| format!("Failed to write CSV row: {e}"))
+                },
+                on_progress,
+            )
+            .await?;
 
             writer
                 .flush()
                 .map_err(|e| format!("Failed to flush CSV writer: {e}"))?;
         }
         ExportFormat::Json => {
-            let file = File::create(&path).map_err(|e| format!("Failed to create JSON file: {e}"))?;
+            let file = File::create(path).map_err(|e| format!("Failed to create JSON file: {e}"))?;
             let mut writer = BufWriter::new(file);
-            let total = entries.len() as u64;
-            let mut processed = 0_u64;
             let mut first = true;
 
             writer
                 .write_all(b"[")
                 .map_err(|e| format!("Failed to start JSON array: {e}"))?;
 
-            let mut pending = entries;
-            let mut set = JoinSet::new();
-
-            loop {
-                while set.len() < 8 {
-                    if let Some(entry) = pending.pop() {
-                        set.spawn(async move { get_link_details(entry.path) });
-                    } else {
-                        break;
-                    }
-                }
-
-                if set.is_empty() {
-                    break;
-                }
-
-                if let Some(result) = set.join_next().await {
-                    let details = result
-                        .map_err(|e| format!("Export worker join error: {e}"))??;
-
+            export_ordered(
+                entries,
+                concurrency,
+                |details| {
                     if !first {
                         writer
                             .write_all(b",")
@@ -105,14 +252,11 @@ pub async fn export_links(
                         .map_err(|e| format!("Failed to write JSON row: {e}"))?;
 
                     first = false;
-                    processed += 1;
-
-                    let _ = app.emit("export:progress", serde_json::json!({
-                        "processed": processed,
-                        "total": total,
-                    }));
-                }
-            }
+                    Ok(())
+                },
+                on_progress,
+            )
+            .await?;
 
             writer
                 .write_all(b"]")
@@ -121,7 +265,51 @@ pub async fn export_links(
                 .flush()
                 .map_err(|e| format!("Failed to flush JSON writer: {e}"))?;
         }
+        ExportFormat::Ndjson => {
+            let file = File::create(path).map_err(|e| format!("Failed to create NDJSON file: {e}"))?;
+            let mut writer = BufWriter::new(file);
+
+            export_ordered(
+                entries,
+                concurrency,
+                |details| {
+                    let json = serde_json::to_vec(&details)
+                        .map_err(|e| format!("Failed to serialize NDJSON row: {e}"))?;
+                    writer
+                        .write_all(&json)
+                        .map_err(|e| format!("Failed to write NDJSON row: {e}"))?;
+                    writer
+                        .write_all(b"\n")
+                        .map_err(|e| format!("Failed to write NDJSON newline: {e}"))
+                },
+                on_progress,
+            )
+            .await?;
+
+            writer
+                .flush()
+                .map_err(|e| format!("Failed to flush NDJSON writer: {e}"))?;
+        }
     }
 
     Ok(())
 }
+
+#[tauri::command]
+pub async fn export_links(
+    app: AppHandle,
+    entries: Vec<LinkEntry>,
+    format: ExportFormat,
+    path: String,
+) -> Result<(), String> {
+    write_export(entries, format, &path, EXPORT_LINKS_CONCURRENCY, move |processed, total| {
+        let _ = app.emit(
+            "export:progress",
+            serde_json::json!({
+                "processed": processed,
+                "total": total,
+            }),
+        );
+    })
+    .await
+}
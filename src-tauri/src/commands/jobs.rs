@@ -0,0 +1,466 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+use walkdir::WalkDir;
+
+use crate::commands::details::classify_status;
+use crate::commands::scan::{
+    describe_hardlink, get_hardlink_info, map_symlink_type, normalize_drive, should_exclude,
+    SCAN_BATCH_SIZE,
+};
+use crate::config::load_config;
+use crate::types::{JobReport, JobState, LinkEntry, LinkType, ScanBatch, ScanMode, ScanProgress};
+
+const CHECKPOINT_FLUSH_ENTRIES: u64 = 200;
+const CHECKPOINT_FLUSH_MILLIS: u128 = 1_000;
+
+pub(crate) struct JobControl {
+    pub(crate) cancel: AtomicBool,
+    pub(crate) pause: AtomicBool,
+}
+
+pub(crate) fn job_controls() -> &'static Mutex<HashMap<String, Arc<JobControl>>> {
+    static CONTROLS: OnceLock<Mutex<HashMap<String, Arc<JobControl>>>> = OnceLock::new();
+    CONTROLS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+pub(crate) fn job_state_to_text(state: JobState) -> &'static str {
+    match state {
+        JobState::Queued => "Queued",
+        JobState::Running => "Running",
+        JobState::Paused => "Paused",
+        JobState::Completed => "Completed",
+        JobState::Failed => "Failed",
+        JobState::Canceled => "Canceled",
+    }
+}
+
+pub(crate) fn job_state_from_text(value: &str) -> JobState {
+    match value {
+        "Running" => JobState::Running,
+        "Paused" => JobState::Paused,
+        "Completed" => JobState::Completed,
+        "Failed" => JobState::Failed,
+        "Canceled" => JobState::Canceled,
+        _ => JobState::Queued,
+    }
+}
+
+fn scan_mode_to_text(mode: &ScanMode) -> &'static str {
+    match mode {
+        ScanMode::UsnJournal => "UsnJournal",
+        ScanMode::WalkdirFallback => "WalkdirFallback",
+    }
+}
+
+fn scan_mode_from_text(value: &str) -> ScanMode {
+    match value {
+        "UsnJournal" => ScanMode::UsnJournal,
+        _ => ScanMode::WalkdirFallback,
+    }
+}
+
+fn upsert_job_report(conn: &Connection, report: &JobReport) -> Result<(), String> {
+    conn.execute(
+        "
+        INSERT INTO scan_jobs (id, mode, scanned, found, current_path, checkpoint_path, state, created_at, updated_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8)
+        ON CONFLICT(id) DO UPDATE SET
+            scanned = excluded.scanned,
+            found = excluded.found,
+            current_path = excluded.current_path,
+            checkpoint_path = excluded.checkpoint_path,
+            state = excluded.state,
+            updated_at = excluded.updated_at
+        ",
+        params![
+            report.id,
+            scan_mode_to_text(&report.mode),
+            report.scanned as i64,
+            report.found as i64,
+            report.current_path,
+            report.checkpoint_path,
+            job_state_to_text(report.state),
+            report.created_at,
+        ],
+    )
+    .map_err(|e| format!("Failed to persist scan job {}: {e}", report.id))?;
+
+    Ok(())
+}
+
+fn load_job_report(conn: &Connection, job_id: &str) -> Result<Option<JobReport>, String> {
+    conn.query_row(
+        "
+        SELECT id, mode, scanned, found, current_path, checkpoint_path, state, created_at, updated_at
+        FROM scan_jobs WHERE id = ?1
+        ",
+        params![job_id],
+        row_to_job_report,
+    )
+    .optional()
+    .map_err(|e| format!("Failed to load scan job {job_id}: {e}"))
+}
+
+fn row_to_job_report(row: &rusqlite::Row) -> rusqlite::Result<JobReport> {
+    Ok(JobReport {
+        id: row.get(0)?,
+        mode: scan_mode_from_text(&row.get::<_, String>(1)?),
+        scanned: row.get::<_, i64>(2)? as u64,
+        found: row.get::<_, i64>(3)? as u64,
+        current_path: row.get(4)?,
+        checkpoint_path: row.get(5)?,
+        state: job_state_from_text(&row.get::<_, String>(6)?),
+        created_at: row.get(7)?,
+        updated_at: row.get(8)?,
+    })
+}
+
+#[tauri::command]
+pub fn list_scan_jobs() -> Result<Vec<JobReport>, String> {
+    let conn = crate::db::open_connection()?;
+
+    let mut stmt = conn
+        .prepare(
+            "
+            SELECT id, mode, scanned, found, current_path, checkpoint_path, state, created_at, updated_at
+            FROM scan_jobs
+            ORDER BY updated_at DESC
+            ",
+        )
+        .map_err(|e| format!("Failed to prepare scan job query: {e}"))?;
+
+    let rows = stmt
+        .query_map([], row_to_job_report)
+        .map_err(|e| format!("Failed to query scan jobs: {e}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to decode scan jobs: {e}"))
+}
+
+fn run_walkdir_job(
+    job_id: String,
+    drive: String,
+    resume_from: Option<String>,
+    app: AppHandle,
+    control: Arc<JobControl>,
+) {
+    let conn = match crate::db::open_connection() {
+        Ok(value) => value,
+        Err(_) => return,
+    };
+
+    let config = load_config().unwrap_or_default();
+    let root = match normalize_drive(&drive) {
+        Ok(value) => value,
+        Err(_) => return,
+    };
+    let root_path = PathBuf::from(&root);
+
+    let mut scanned = 0_u64;
+    let mut found = 0_u64;
+    let mut entries: Vec<LinkEntry> = Vec::new();
+    let mut batch: Vec<LinkEntry> = Vec::with_capacity(SCAN_BATCH_SIZE);
+    let mut seen_hardlinks: std::collections::HashSet<(u32, u64)> = std::collections::HashSet::new();
+    let mut last_checkpoint = resume_from.clone();
+    let mut last_flush = std::time::Instant::now();
+    let mut paused = false;
+
+    // `WalkDir` yields a pre-order DFS sorted by file name at each level, not
+    // full-path lexicographic order (e.g. `C:\a.txt` visits after `C:\a\x`
+    // despite sorting before it as a string), so a resume boundary can only
+    // be honored by replaying that exact sequence and watching for the
+    // recorded entry go by — never by comparing path strings. `contents_first`
+    // additionally flips directories to be yielded after their contents
+    // instead of before, so a checkpoint recorded against a directory means
+    // its whole subtree is done, not just that the walk had reached it.
+    let mut resume_boundary = resume_from.clone();
+
+    let walker = WalkDir::new(&root_path)
+        .follow_links(false)
+        .contents_first(true)
+        .sort_by(|a, b| a.file_name().cmp(b.file_name()));
+
+    for item in walker.into_iter().filter_map(Result::ok) {
+        if control.cancel.load(Ordering::SeqCst) {
+            persist_state(&conn, &job_id, scanned, found, &last_checkpoint, JobState::Canceled);
+            return;
+        }
+
+        if control.pause.load(Ordering::SeqCst) {
+            emit_batch(&app, &mut batch);
+            persist_state(&conn, &job_id, scanned, found, &last_checkpoint, JobState::Paused);
+            paused = true;
+            break;
+        }
+
+        let path = item.path().to_path_buf();
+        let path_text = path.to_string_lossy().to_string();
+
+        if let Some(boundary) = &resume_boundary {
+            let reached_boundary = path_text.as_str() == boundary.as_str();
+            if reached_boundary {
+                resume_boundary = None;
+            }
+            continue;
+        }
+
+        if should_exclude(&path, &config.scan.excluded_paths) {
+            continue;
+        }
+
+        scanned += 1;
+        last_checkpoint = Some(path_text.clone());
+
+        let metadata = match fs::symlink_metadata(&path) {
+            Ok(value) => value,
+            Err(_) => continue,
+        };
+        let file_type = metadata.file_type();
+
+        if file_type.is_symlink() {
+            let target = fs::read_link(&path)
+                .map(|value| value.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let link_type = map_symlink_type(&path, &target);
+            let status = classify_status(&path_text);
+
+            let entry = LinkEntry {
+                path: path_text.clone(),
+                target,
+                link_type,
+                status,
+                hardlink_siblings: Vec::new(),
+            };
+            batch.push(entry.clone());
+            entries.push(entry);
+            found += 1;
+        } else if !metadata.is_dir() {
+            if let Ok((volume_serial, file_index, links_count)) = get_hardlink_info(&path_text) {
+                if links_count > 1 && seen_hardlinks.insert((volume_serial, file_index)) {
+                    let status = classify_status(&path_text);
+                    let (target, hardlink_siblings) = describe_hardlink(&path);
+                    let entry = LinkEntry {
+                        path: path_text.clone(),
+                        target,
+                        link_type: LinkType::Hardlink,
+                        status,
+                        hardlink_siblings,
+                    };
+                    batch.push(entry.clone());
+                    entries.push(entry);
+                    found += 1;
+                }
+            }
+        }
+
+        if batch.len() >= SCAN_BATCH_SIZE {
+            emit_batch(&app, &mut batch);
+        }
+
+        let _ = app.emit(
+            "scan:progress",
+            ScanProgress {
+                scanned,
+                found,
+                current_path: path_text,
+            },
+        );
+
+        if scanned % CHECKPOINT_FLUSH_ENTRIES == 0 || last_flush.elapsed().as_millis() >= CHECKPOINT_FLUSH_MILLIS {
+            persist_state(&conn, &job_id, scanned, found, &last_checkpoint, JobState::Running);
+            last_flush = std::time::Instant::now();
+        }
+    }
+
+    if paused {
+        return;
+    }
+
+    emit_batch(&app, &mut batch);
+    persist_state(&conn, &job_id, scanned, found, &last_checkpoint, JobState::Completed);
+}
+
+fn emit_batch(app: &AppHandle, batch: &mut Vec<LinkEntry>) {
+    if batch.is_empty() {
+        return;
+    }
+
+    let payload = ScanBatch {
+        entries: std::mem::take(batch),
+    };
+    let _ = app.emit("scan:batch", payload);
+}
+
+fn persist_state(
+    conn: &Connection,
+    job_id: &str,
+    scanned: u64,
+    found: u64,
+    checkpoint_path: &Option<String>,
+    state: JobState,
+) {
+    let existing = load_job_report(conn, job_id).ok().flatten();
+    let created_at = existing
+        .map(|value| value.created_at)
+        .unwrap_or_else(|| Utc::now().to_rfc3339());
+
+    let report = JobReport {
+        id: job_id.to_string(),
+        mode: ScanMode::WalkdirFallback,
+        scanned,
+        found,
+        current_path: checkpoint_path.clone().unwrap_or_default(),
+        checkpoint_path: checkpoint_path.clone(),
+        state,
+        created_at,
+        updated_at: Utc::now().to_rfc3339(),
+    };
+
+    let _ = upsert_job_report(conn, &report);
+    let _ = state;
+}
+
+fn spawn_job(job_id: String, drive: String, resume_from: Option<String>, app: AppHandle) -> Result<(), String> {
+    let control = Arc::new(JobControl {
+        cancel: AtomicBool::new(false),
+        pause: AtomicBool::new(false),
+    });
+
+    job_controls()
+        .lock()
+        .map_err(|_| "Job registry lock poisoned".to_string())?
+        .insert(job_id.clone(), control.clone());
+
+    let conn = crate::db::open_connection()?;
+    let now = Utc::now().to_rfc3339();
+    upsert_job_report(
+        &conn,
+        &JobReport {
+            id: job_id.clone(),
+            mode: ScanMode::WalkdirFallback,
+            scanned: 0,
+            found: 0,
+            current_path: resume_from.clone().unwrap_or_default(),
+            checkpoint_path: resume_from.clone(),
+            state: JobState::Running,
+            created_at: now.clone(),
+            updated_at: now,
+        },
+    )?;
+
+    std::thread::spawn(move || run_walkdir_job(job_id, drive, resume_from, app, control));
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn start_scan_job(drive: String, app: AppHandle) -> Result<String, String> {
+    let job_id = Uuid::new_v4().to_string();
+    spawn_job(job_id.clone(), drive, None, app)?;
+    Ok(job_id)
+}
+
+#[tauri::command]
+pub fn pause_scan(job_id: String) -> Result<(), String> {
+    let controls = job_controls()
+        .lock()
+        .map_err(|_| "Job registry lock poisoned".to_string())?;
+
+    let control = controls
+        .get(&job_id)
+        .ok_or_else(|| format!("No active job with id {job_id}"))?;
+
+    control.pause.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn resume_scan(job_id: String, app: AppHandle) -> Result<(), String> {
+    let conn = crate::db::open_connection()?;
+    let report = load_job_report(&conn, &job_id)?.ok_or_else(|| format!("Unknown job {job_id}"))?;
+
+    if report.state != JobState::Paused && report.state != JobState::Failed {
+        return Err(format!("Job {job_id} is not paused or failed"));
+    }
+
+    let drive = resume_drive_from_checkpoint(&report)?;
+    spawn_job(job_id, drive, report.checkpoint_path, app)
+}
+
+fn resume_drive_from_checkpoint(report: &JobReport) -> Result<String, String> {
+    let reference = if !report.current_path.is_empty() {
+        report.current_path.clone()
+    } else {
+        report
+            .checkpoint_path
+            .clone()
+            .ok_or_else(|| "Job has no recorded path to resume from".to_string())?
+    };
+
+    Path::new(&reference)
+        .components()
+        .next()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .ok_or_else(|| "Unable to resolve drive from checkpoint path".to_string())
+}
+
+#[tauri::command]
+pub fn cancel_scan(job_id: String) -> Result<(), String> {
+    let controls = job_controls()
+        .lock()
+        .map_err(|_| "Job registry lock poisoned".to_string())?;
+
+    if let Some(control) = controls.get(&job_id) {
+        control.cancel.store(true, Ordering::SeqCst);
+        return Ok(());
+    }
+
+    let conn = crate::db::open_connection()?;
+    let report = load_job_report(&conn, &job_id)?.ok_or_else(|| format!("Unknown job {job_id}"))?;
+    persist_state(&conn, &job_id, report.scanned, report.found, &report.checkpoint_path, JobState::Canceled);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn job_state_round_trips_through_text() {
+        for state in [
+            JobState::Queued,
+            JobState::Running,
+            JobState::Paused,
+            JobState::Completed,
+            JobState::Failed,
+            JobState::Canceled,
+        ] {
+            assert_eq!(job_state_from_text(job_state_to_text(state)), state);
+        }
+    }
+
+    #[test]
+    fn resume_drive_is_extracted_from_checkpoint_path() {
+        let report = JobReport {
+            id: "job-1".to_string(),
+            mode: ScanMode::WalkdirFallback,
+            scanned: 10,
+            found: 2,
+            current_path: "C:\\Users\\example\\dir".to_string(),
+            checkpoint_path: Some("C:\\Users\\example\\dir".to_string()),
+            state: JobState::Paused,
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            updated_at: "2025-01-01T00:00:00Z".to_string(),
+        };
+
+        assert_eq!(resume_drive_from_checkpoint(&report).unwrap(), "C:");
+    }
+}
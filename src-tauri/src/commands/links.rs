@@ -2,11 +2,19 @@ use std::fs;
 use std::path::Path;
 use std::process::Command;
 
+use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter};
+use tokio::task::JoinSet;
+use windows_sys::Win32::Storage::FileSystem::{
+    GetFileAttributesW, SetFileAttributesW, FILE_ATTRIBUTE_HIDDEN, FILE_ATTRIBUTE_READONLY,
+    FILE_ATTRIBUTE_SYSTEM, INVALID_FILE_ATTRIBUTES,
+};
 
-use crate::db::history::{log_action, ActionInput};
+use crate::db::history::{begin_batch, log_action, log_grouped_action, ActionInput};
 use crate::types::LinkType;
 
+const LINK_BATCH_CONCURRENCY: usize = 8;
+
 fn map_error(error: std::io::Error) -> String {
     if let Some(code) = error.raw_os_error() {
         if code == 1314 {
@@ -55,13 +63,13 @@ fn read_target(path: &str) -> String {
         .unwrap_or_else(|_| path.to_string())
 }
 
-pub fn create_link_internal(
+pub async fn create_link_internal(
     link_path: &str,
     target_path: &str,
     link_type: &LinkType,
     target_is_dir: bool,
 ) -> Result<(), String> {
-    if Path::new(link_path).exists() {
+    if tokio::fs::metadata(link_path).await.is_ok() {
         return Err("Link path already exists".to_string());
     }
 
@@ -69,16 +77,20 @@ pub fn create_link_internal(
         .parent()
         .ok_or_else(|| "Link path has no parent directory".to_string())?;
 
-    if !parent.exists() {
-        fs::create_dir_all(parent).map_err(map_error)?;
+    if tokio::fs::metadata(parent).await.is_err() {
+        tokio::fs::create_dir_all(parent).await.map_err(map_error)?;
     }
 
     match link_type {
         LinkType::Symlink => {
             if Path::new(target_path).is_dir() || target_is_dir {
-                std::os::windows::fs::symlink_dir(target_path, link_path).map_err(map_error)?;
+                tokio::fs::symlink_dir(target_path, link_path)
+                    .await
+                    .map_err(map_error)?;
             } else {
-                std::os::windows::fs::symlink_file(target_path, link_path).map_err(map_error)?;
+                tokio::fs::symlink_file(target_path, link_path)
+                    .await
+                    .map_err(map_error)?;
             }
         }
         LinkType::Junction => {
@@ -86,9 +98,10 @@ pub fn create_link_internal(
                 return Err("Junction target must be an absolute path".to_string());
             }
 
-            let status = Command::new("cmd")
+            let status = tokio::process::Command::new("cmd")
                 .args(["/C", "mklink", "/J", link_path, target_path])
                 .status()
+                .await
                 .map_err(|e| format!("Failed to create junction: {e}"))?;
 
             if !status.success() {
@@ -107,65 +120,61 @@ pub fn create_link_internal(
                 return Err("Hardlink requires source and target to be on the same volume".to_string());
             }
 
-            fs::hard_link(target_path, link_path).map_err(map_error)?;
+            tokio::fs::hard_link(target_path, link_path)
+                .await
+                .map_err(map_error)?;
         }
     }
 
     Ok(())
 }
 
-pub fn delete_link_internal(path: &str) -> Result<(), String> {
-    let metadata = fs::symlink_metadata(path).map_err(map_error)?;
+async fn remove_dir_link(path: &str) -> Result<(), String> {
+    if let Err(error) = tokio::fs::remove_dir(path).await {
+        if error.raw_os_error() == Some(5) {
+            let status = tokio::process::Command::new("cmd")
+                .args(["/C", "rmdir", path])
+                .status()
+                .await
+                .map_err(|e| format!("Failed to remove directory link: {e}"))?;
+
+            if !status.success() {
+                return Err(map_error(error));
+            }
+        } else {
+            return Err(map_error(error));
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn delete_link_internal(path: &str) -> Result<(), String> {
+    let metadata = tokio::fs::symlink_metadata(path).await.map_err(map_error)?;
 
     if metadata.file_type().is_symlink() {
         if metadata.is_dir() {
-            if let Err(error) = fs::remove_dir(path) {
-                if error.raw_os_error() == Some(5) {
-                    let status = Command::new("cmd")
-                        .args(["/C", "rmdir", path])
-                        .status()
-                        .map_err(|e| format!("Failed to remove directory link: {e}"))?;
-
-                    if !status.success() {
-                        return Err(map_error(error));
-                    }
-                } else {
-                    return Err(map_error(error));
-                }
-            }
+            remove_dir_link(path).await?;
         } else {
-            fs::remove_file(path).map_err(map_error)?;
+            tokio::fs::remove_file(path).await.map_err(map_error)?;
         }
     } else if metadata.is_dir() {
-        if let Err(error) = fs::remove_dir(path) {
-            if error.raw_os_error() == Some(5) {
-                let status = Command::new("cmd")
-                    .args(["/C", "rmdir", path])
-                    .status()
-                    .map_err(|e| format!("Failed to remove directory link: {e}"))?;
-
-                if !status.success() {
-                    return Err(map_error(error));
-                }
-            } else {
-                return Err(map_error(error));
-            }
-        }
+        remove_dir_link(path).await?;
     } else {
-        fs::remove_file(path).map_err(map_error)?;
+        tokio::fs::remove_file(path).await.map_err(map_error)?;
     }
 
     Ok(())
 }
 
-pub fn retarget_link_internal(path: &str, new_target: &str) -> Result<(), String> {
+pub async fn retarget_link_internal(path: &str, new_target: &str) -> Result<(), String> {
     let link_type = detect_link_type(path)?;
     let old_target = read_target(path);
 
-    delete_link_internal(path)?;
+    delete_link_internal(path).await?;
 
-    if let Err(error) = create_link_internal(path, new_target, &link_type, false) {
-        let _ = create_link_internal(path, &old_target, &link_type, false);
+    if let Err(error) = create_link_internal(path, new_target, &link_type, false).await {
+        let _ = create_link_internal(path, &old_target, &link_type, false).await;
         return Err(error);
     }
 
@@ -173,14 +182,14 @@ pub fn retarget_link_internal(path: &str, new_target: &str) -> Result<(), String
 }
 
 #[tauri::command]
-pub fn create_link(
+pub async fn create_link(
     app: AppHandle,
     link_path: String,
     target_path: String,
     link_type: LinkType,
     target_is_dir: bool,
 ) -> Result<(), String> {
-    let operation = create_link_internal(&link_path, &target_path, &link_type, target_is_dir);
+    let operation = create_link_internal(&link_path, &target_path, &link_type, target_is_dir).await;
 
     let conn = crate::db::open_connection()?;
 
@@ -211,11 +220,11 @@ pub fn create_link(
 }
 
 #[tauri::command]
-pub fn delete_link(app: AppHandle, path: String) -> Result<(), String> {
+pub async fn delete_link(app: AppHandle, path: String) -> Result<(), String> {
     let link_type = detect_link_type(&path)?;
     let target_old = Some(read_target(&path));
 
-    let operation = delete_link_internal(&path);
+    let operation = delete_link_internal(&path).await;
 
     let conn = crate::db::open_connection()?;
     let (success, error_msg) = match operation {
@@ -245,11 +254,11 @@ pub fn delete_link(app: AppHandle, path: String) -> Result<(), String> {
 }
 
 #[tauri::command]
-pub fn retarget_link(app: AppHandle, path: String, new_target: String) -> Result<(), String> {
+pub async fn retarget_link(app: AppHandle, path: String, new_target: String) -> Result<(), String> {
     let link_type = detect_link_type(&path)?;
     let old_target = Some(read_target(&path));
 
-    let operation = retarget_link_internal(&path, &new_target);
+    let operation = retarget_link_internal(&path, &new_target).await;
 
     let conn = crate::db::open_connection()?;
     let (success, error_msg) = match operation {
@@ -292,8 +301,398 @@ pub fn open_target(target: String) -> Result<(), String> {
     Ok(())
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateLinkOp {
+    pub link_path: String,
+    pub target_path: String,
+    pub link_type: LinkType,
+    pub target_is_dir: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetargetLinkOp {
+    pub path: String,
+    pub new_target: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkOpResult {
+    pub link_path: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+async fn log_and_result(
+    group_id: &str,
+    action_type: &str,
+    link_path: String,
+    link_type: LinkType,
+    target_old: Option<String>,
+    target_new: Option<String>,
+    operation: Result<(), String>,
+) -> LinkOpResult {
+    let (success, error_msg) = match &operation {
+        Ok(_) => (true, None),
+        Err(error) => (false, Some(error.clone())),
+    };
+
+    if let Ok(conn) = crate::db::open_connection() {
+        let _ = log_grouped_action(
+            &conn,
+            ActionInput {
+                action_type: action_type.to_string(),
+                link_path: link_path.clone(),
+                link_type,
+                target_old,
+                target_new,
+                success,
+                error_msg: error_msg.clone(),
+            },
+            group_id,
+        );
+    }
+
+    LinkOpResult {
+        link_path,
+        success,
+        error: error_msg,
+    }
+}
+
+#[tauri::command]
+pub async fn create_links(app: AppHandle, ops: Vec<CreateLinkOp>) -> Vec<LinkOpResult> {
+    let group_id = begin_batch();
+
+    let mut pending = ops;
+    let mut set = JoinSet::new();
+    let mut results = Vec::new();
+    let mut created_paths = Vec::new();
+
+    loop {
+        while set.len() < LINK_BATCH_CONCURRENCY {
+            let Some(op) = pending.pop() else { break };
+
+            let group_id = group_id.clone();
+            set.spawn(async move {
+                let operation =
+                    create_link_internal(&op.link_path, &op.target_path, &op.link_type, op.target_is_dir).await;
+                let ok = operation.is_ok();
+                let result = log_and_result(
+                    &group_id,
+                    "Create",
+                    op.link_path.clone(),
+                    op.link_type,
+                    None,
+                    Some(op.target_path),
+                    operation,
+                )
+                .await;
+                (ok, result)
+            });
+        }
+
+        let Some(joined) = set.join_next().await else {
+            break;
+        };
+
+        let (ok, result) = match joined {
+            Ok(value) => value,
+            Err(error) => continue_after_panic(error),
+        };
+
+        if ok {
+            created_paths.push(result.link_path.clone());
+        }
+        results.push(result);
+    }
+
+    if !created_paths.is_empty() {
+        let _ = app.emit("links:created", &created_paths);
+    }
+
+    results
+}
+
+#[tauri::command]
+pub async fn delete_links(app: AppHandle, paths: Vec<String>) -> Vec<LinkOpResult> {
+    let group_id = begin_batch();
+
+    let mut pending = paths;
+    let mut set = JoinSet::new();
+    let mut results = Vec::new();
+    let mut deleted_paths = Vec::new();
+
+    loop {
+        while set.len() < LINK_BATCH_CONCURRENCY {
+            let Some(path) = pending.pop() else { break };
+
+            let group_id = group_id.clone();
+            set.spawn(async move {
+                let link_type = detect_link_type(&path).unwrap_or(LinkType::Symlink);
+                let target_old = Some(read_target(&path));
+                let operation = delete_link_internal(&path).await;
+                let ok = operation.is_ok();
+                let result =
+                    log_and_result(&group_id, "Delete", path.clone(), link_type, target_old, None, operation).await;
+                (ok, result)
+            });
+        }
+
+        let Some(joined) = set.join_next().await else {
+            break;
+        };
+
+        let (ok, result) = match joined {
+            Ok(value) => value,
+            Err(error) => continue_after_panic(error),
+        };
+
+        if ok {
+            deleted_paths.push(result.link_path.clone());
+        }
+        results.push(result);
+    }
+
+    if !deleted_paths.is_empty() {
+        let _ = app.emit("links:deleted", &deleted_paths);
+    }
+
+    results
+}
+
+#[tauri::command]
+pub async fn retarget_links(app: AppHandle, ops: Vec<RetargetLinkOp>) -> Vec<LinkOpResult> {
+    let group_id = begin_batch();
+
+    let mut pending = ops;
+    let mut set = JoinSet::new();
+    let mut results = Vec::new();
+    let mut retargeted_paths = Vec::new();
+
+    loop {
+        while set.len() < LINK_BATCH_CONCURRENCY {
+            let Some(op) = pending.pop() else { break };
+
+            let group_id = group_id.clone();
+            set.spawn(async move {
+                let link_type = detect_link_type(&op.path).unwrap_or(LinkType::Symlink);
+                let target_old = Some(read_target(&op.path));
+                let operation = retarget_link_internal(&op.path, &op.new_target).await;
+                let ok = operation.is_ok();
+                let result = log_and_result(
+                    &group_id,
+                    "Retarget",
+                    op.path.clone(),
+                    link_type,
+                    target_old,
+                    Some(op.new_target),
+                    operation,
+                )
+                .await;
+                (ok, result)
+            });
+        }
+
+        let Some(joined) = set.join_next().await else {
+            break;
+        };
+
+        let (ok, result) = match joined {
+            Ok(value) => value,
+            Err(error) => continue_after_panic(error),
+        };
+
+        if ok {
+            retargeted_paths.push(result.link_path.clone());
+        }
+        results.push(result);
+    }
+
+    if !retargeted_paths.is_empty() {
+        let _ = app.emit("links:retargeted", &retargeted_paths);
+    }
+
+    results
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkAttributeUpdate {
+    pub hidden: Option<bool>,
+    pub read_only: Option<bool>,
+    pub system: Option<bool>,
+    pub owner: Option<String>,
+}
+
+fn to_wide_null(value: &str) -> Vec<u16> {
+    std::os::windows::ffi::OsStrExt::encode_wide(std::ffi::OsStr::new(value))
+        .chain(std::iter::once(0))
+        .collect()
+}
+
+fn describe_attributes(flags: u32) -> String {
+    let mut names = Vec::new();
+
+    if flags & FILE_ATTRIBUTE_HIDDEN != 0 {
+        names.push("HIDDEN");
+    }
+    if flags & FILE_ATTRIBUTE_READONLY != 0 {
+        names.push("READONLY");
+    }
+    if flags & FILE_ATTRIBUTE_SYSTEM != 0 {
+        names.push("SYSTEM");
+    }
+
+    if names.is_empty() {
+        "NORMAL".to_string()
+    } else {
+        names.join(",")
+    }
+}
+
+fn map_attribute_error(error: std::io::Error) -> String {
+    if error.raw_os_error() == Some(5) {
+        return "Access is denied. Enable Developer Mode in Windows Settings or run as Administrator to change attributes or ownership on this path.".to_string();
+    }
+
+    error.to_string()
+}
+
+fn read_attributes(path: &str) -> Result<u32, String> {
+    let wide = to_wide_null(path);
+    let flags = unsafe { GetFileAttributesW(wide.as_ptr()) };
+
+    if flags == INVALID_FILE_ATTRIBUTES {
+        return Err(map_attribute_error(std::io::Error::last_os_error()));
+    }
+
+    Ok(flags)
+}
+
+fn write_attributes(path: &str, flags: u32) -> Result<(), String> {
+    let wide = to_wide_null(path);
+    let ok = unsafe { SetFileAttributesW(wide.as_ptr(), flags) };
+
+    if ok == 0 {
+        return Err(map_attribute_error(std::io::Error::last_os_error()));
+    }
+
+    Ok(())
+}
+
+fn set_owner(path: &str, owner: &str) -> Result<(), String> {
+    let status = Command::new("icacls")
+        .args([path, "/setowner", owner])
+        .status()
+        .map_err(|e| format!("Failed to invoke icacls: {e}"))?;
+
+    if !status.success() {
+        return Err(format!("icacls failed to set owner of {path} to {owner}"));
+    }
+
+    Ok(())
+}
+
+fn apply_attribute_update(path: &str, update: &LinkAttributeUpdate) -> Result<(u32, u32), String> {
+    let before = read_attributes(path)?;
+    let mut after = before;
+
+    if let Some(hidden) = update.hidden {
+        after = if hidden {
+            after | FILE_ATTRIBUTE_HIDDEN
+        } else {
+            after & !FILE_ATTRIBUTE_HIDDEN
+        };
+    }
+
+    if let Some(read_only) = update.read_only {
+        after = if read_only {
+            after | FILE_ATTRIBUTE_READONLY
+        } else {
+            after & !FILE_ATTRIBUTE_READONLY
+        };
+    }
+
+    if let Some(system) = update.system {
+        after = if system {
+            after | FILE_ATTRIBUTE_SYSTEM
+        } else {
+            after & !FILE_ATTRIBUTE_SYSTEM
+        };
+    }
+
+    if after != before {
+        write_attributes(path, after)?;
+    }
+
+    if let Some(owner) = &update.owner {
+        set_owner(path, owner).map_err(map_attribute_error_string)?;
+    }
+
+    Ok((before, after))
+}
+
+fn map_attribute_error_string(error: String) -> String {
+    if error.contains("os error 5") {
+        return "Access is denied. Enable Developer Mode in Windows Settings or run as Administrator to change attributes or ownership on this path.".to_string();
+    }
+
+    error
+}
+
+/// Logged as `SetPermissions`, which `latest_undo_candidate` treats as
+/// irreversible and skips over rather than handing to `undo_last` — there's
+/// no target/type change here for `apply_reverse` to put back.
+#[tauri::command]
+pub fn set_link_attributes(path: String, update: LinkAttributeUpdate) -> Result<(), String> {
+    let operation = apply_attribute_update(&path, &update);
+
+    let conn = crate::db::open_connection()?;
+    let link_type = detect_link_type(&path).unwrap_or(LinkType::Symlink);
+
+    let (success, error_msg, target_old, target_new) = match &operation {
+        Ok((before, after)) => (true, None, Some(describe_attributes(*before)), Some(describe_attributes(*after))),
+        Err(error) => (false, Some(error.clone()), None, None),
+    };
+
+    log_action(
+        &conn,
+        ActionInput {
+            action_type: "SetPermissions".to_string(),
+            link_path: path,
+            link_type,
+            target_old,
+            target_new,
+            success,
+            error_msg: error_msg.clone(),
+        },
+    )?;
+
+    if let Some(message) = error_msg {
+        return Err(message);
+    }
+
+    Ok(())
+}
+
+fn continue_after_panic(error: tokio::task::JoinError) -> (bool, LinkOpResult) {
+    (
+        false,
+        LinkOpResult {
+            link_path: "<unknown>".to_string(),
+            success: false,
+            error: Some(format!("link worker crashed: {error}")),
+        },
+    )
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn has_junction_prefix_logic() {
         let path = "C:\\root\\item";
@@ -301,4 +700,14 @@ mod tests {
         let same_volume = path.chars().take(2).collect::<String>() == target.chars().take(2).collect::<String>();
         assert!(same_volume);
     }
+
+    #[test]
+    fn describe_attributes_lists_set_flags() {
+        assert_eq!(describe_attributes(0), "NORMAL");
+        assert_eq!(describe_attributes(FILE_ATTRIBUTE_HIDDEN), "HIDDEN");
+        assert_eq!(
+            describe_attributes(FILE_ATTRIBUTE_HIDDEN | FILE_ATTRIBUTE_READONLY),
+            "HIDDEN,READONLY"
+        );
+    }
 }
@@ -0,0 +1,234 @@
+use std::fs;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::links::{create_link_internal, delete_link_internal};
+use crate::db::history::{log_action, ActionInput};
+use crate::types::{LinkDetails, LinkType, ObjectType};
+
+const MANIFEST_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestEntry {
+    pub path: String,
+    pub target_stored: String,
+    pub target_real: String,
+    pub link_type: LinkType,
+    pub object_type: ObjectType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+    pub format_version: u32,
+    pub created_at: String,
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl From<LinkDetails> for ManifestEntry {
+    fn from(details: LinkDetails) -> Self {
+        Self {
+            path: details.path,
+            target_stored: details.target_stored,
+            target_real: details.target_real,
+            link_type: details.link_type,
+            object_type: details.object_type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RestoreAction {
+    Created,
+    Overwritten,
+    Skipped,
+    NoOp,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreResult {
+    pub path: String,
+    pub action: RestoreAction,
+    pub error: Option<String>,
+}
+
+#[tauri::command]
+pub fn create_manifest(entries: Vec<LinkDetails>, path: String) -> Result<(), String> {
+    let manifest = Manifest {
+        format_version: MANIFEST_FORMAT_VERSION,
+        created_at: Utc::now().to_rfc3339(),
+        entries: entries.into_iter().map(ManifestEntry::from).collect(),
+    };
+
+    let file = File::create(&path).map_err(|e| format!("Failed to create manifest file: {e}"))?;
+    serde_json::to_writer_pretty(BufWriter::new(file), &manifest)
+        .map_err(|e| format!("Failed to serialize manifest: {e}"))
+}
+
+fn read_manifest(path: &str) -> Result<Manifest, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open manifest file: {e}"))?;
+    serde_json::from_reader(BufReader::new(file)).map_err(|e| format!("Failed to parse manifest: {e}"))
+}
+
+fn existing_target(path: &str) -> Option<String> {
+    fs::read_link(path)
+        .map(|value| value.to_string_lossy().to_string())
+        .ok()
+}
+
+#[tauri::command]
+pub async fn restore_manifest(path: String, dry_run: bool, overwrite: bool) -> Result<Vec<RestoreResult>, String> {
+    let manifest = read_manifest(&path)?;
+    let mut results = Vec::with_capacity(manifest.entries.len());
+
+    for entry in manifest.entries {
+        results.push(restore_entry(entry, dry_run, overwrite).await);
+    }
+
+    Ok(results)
+}
+
+async fn restore_entry(entry: ManifestEntry, dry_run: bool, overwrite: bool) -> RestoreResult {
+    let link_exists = Path::new(&entry.path).exists() || fs::symlink_metadata(&entry.path).is_ok();
+
+    if link_exists {
+        if existing_target(&entry.path).as_deref() == Some(entry.target_stored.as_str()) {
+            return RestoreResult {
+                path: entry.path,
+                action: RestoreAction::NoOp,
+                error: None,
+            };
+        }
+
+        if !overwrite {
+            return RestoreResult {
+                path: entry.path,
+                action: RestoreAction::Skipped,
+                error: None,
+            };
+        }
+
+        if dry_run {
+            return RestoreResult {
+                path: entry.path,
+                action: RestoreAction::Overwritten,
+                error: None,
+            };
+        }
+
+        if let Err(error) = delete_link_internal(&entry.path).await {
+            return RestoreResult {
+                path: entry.path,
+                action: RestoreAction::Failed,
+                error: Some(error),
+            };
+        }
+
+        return apply_create(entry, RestoreAction::Overwritten).await;
+    }
+
+    if dry_run {
+        return RestoreResult {
+            path: entry.path,
+            action: RestoreAction::Created,
+            error: None,
+        };
+    }
+
+    apply_create(entry, RestoreAction::Created).await
+}
+
+/// Logged as `Restore`, which `latest_undo_candidate` treats as irreversible
+/// and skips over rather than handing to `undo_last` — `apply_reverse` only
+/// knows how to invert `Create`/`Delete`/`Retarget`.
+async fn apply_create(entry: ManifestEntry, action_on_success: RestoreAction) -> RestoreResult {
+    let target_is_dir = matches!(entry.object_type, ObjectType::Directory);
+    let operation = create_link_internal(&entry.path, &entry.target_stored, &entry.link_type, target_is_dir).await;
+
+    let (success, error_msg) = match &operation {
+        Ok(_) => (true, None),
+        Err(error) => (false, Some(error.clone())),
+    };
+
+    if let Ok(conn) = crate::db::open_connection() {
+        let _ = log_action(
+            &conn,
+            ActionInput {
+                action_type: "Restore".to_string(),
+                link_path: entry.path.clone(),
+                link_type: entry.link_type,
+                target_old: None,
+                target_new: Some(entry.target_stored.clone()),
+                success,
+                error_msg: error_msg.clone(),
+            },
+        );
+    }
+
+    match error_msg {
+        Some(error) => RestoreResult {
+            path: entry.path,
+            action: RestoreAction::Failed,
+            error: Some(error),
+        },
+        None => RestoreResult {
+            path: entry.path,
+            action: action_on_success,
+            error: None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::LinkStatus;
+
+    fn sample_details(path: &str, target_stored: &str) -> LinkDetails {
+        LinkDetails {
+            path: path.to_string(),
+            target_real: target_stored.to_string(),
+            target_stored: target_stored.to_string(),
+            link_type: LinkType::Symlink,
+            object_type: ObjectType::File,
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            modified_at: "2025-01-01T00:00:00Z".to_string(),
+            owner: "owner".to_string(),
+            attributes: vec!["NORMAL".to_string()],
+            status: LinkStatus::Ok,
+        }
+    }
+
+    #[test]
+    fn manifest_entry_carries_stored_vs_real_target() {
+        let entry = ManifestEntry::from(sample_details("C:\\tmp\\link", "C:\\tmp\\target"));
+
+        assert_eq!(entry.path, "C:\\tmp\\link");
+        assert_eq!(entry.target_stored, "C:\\tmp\\target");
+        assert_eq!(entry.target_real, "C:\\tmp\\target");
+    }
+
+    #[test]
+    fn manifest_round_trips_through_json() {
+        let manifest = Manifest {
+            format_version: MANIFEST_FORMAT_VERSION,
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            entries: vec![ManifestEntry::from(sample_details("C:\\tmp\\link", "C:\\tmp\\target"))],
+        };
+
+        let json = serde_json::to_string(&manifest).expect("serialize manifest");
+        let parsed: Manifest = serde_json::from_str(&json).expect("parse manifest");
+
+        assert_eq!(parsed.format_version, MANIFEST_FORMAT_VERSION);
+        assert_eq!(parsed.entries.len(), 1);
+        assert_eq!(parsed.entries[0].path, "C:\\tmp\\link");
+    }
+}
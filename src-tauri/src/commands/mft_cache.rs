@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::commands::export::write_atomic;
+use crate::commands::scan::FrnNode;
+use crate::config::symview_dir;
+use crate::types::{LinkStatus, LinkType};
+
+const CACHE_MAGIC: [u8; 4] = *b"SVMC";
+const CACHE_VERSION: u32 = 2;
+
+/// A previously-resolved reparse point or hardlink, keyed by FRN in
+/// `MftCache::entries`. An incremental rescan reuses these verbatim for
+/// every FRN the USN journal delta didn't touch, instead of re-walking the
+/// path and re-querying the reparse tag / hardlink count.
+#[derive(Clone)]
+pub(crate) struct CachedEntry {
+    pub(crate) path: String,
+    pub(crate) target: String,
+    pub(crate) link_type: LinkType,
+    pub(crate) status: LinkStatus,
+    pub(crate) hardlink_key: Option<(u32, u64)>,
+    /// Every other path name sharing this entry's hardlink identity,
+    /// excluding `path`. Always empty unless `link_type` is `Hardlink`.
+    pub(crate) hardlink_siblings: Vec<String>,
+}
+
+/// Persisted state for one drive's USN-journal scan: the FRN topology
+/// (`nodes`) needed to rebuild paths, the last resolved link per FRN
+/// (`entries`), and the journal watermark (`usn_journal_id`/`next_usn`)
+/// that tells the next scan whether it can resume from here or must fall
+/// back to a full `FSCTL_ENUM_USN_DATA` walk.
+pub(crate) struct MftCache {
+    pub(crate) usn_journal_id: u64,
+    pub(crate) next_usn: i64,
+    pub(crate) nodes: HashMap<u64, FrnNode>,
+    pub(crate) entries: HashMap<u64, CachedEntry>,
+}
+
+fn cache_path(drive: &str) -> Result<PathBuf, String> {
+    let letter = drive.trim_end_matches(['\\', ':']).to_ascii_lowercase();
+    let dir = symview_dir()?.join("mft-cache");
+
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create mft cache directory: {e}"))?;
+
+    Ok(dir.join(format!("{letter}.cache")))
+}
+
+fn link_type_tag(link_type: &LinkType) -> u8 {
+    match link_type {
+        LinkType::Symlink => 0,
+        LinkType::Junction => 1,
+        LinkType::Hardlink => 2,
+    }
+}
+
+fn link_type_from_tag(tag: u8) -> LinkType {
+    match tag {
+        1 => LinkType::Junction,
+        2 => LinkType::Hardlink,
+        _ => LinkType::Symlink,
+    }
+}
+
+fn status_tag(status: &LinkStatus) -> u8 {
+    match status {
+        LinkStatus::Ok => 0,
+        LinkStatus::Broken(_) => 1,
+        LinkStatus::AccessDenied => 2,
+        LinkStatus::Recursive => 3,
+        LinkStatus::PolicyViolation(_) => 4,
+        LinkStatus::Cyclic => 5,
+    }
+}
+
+fn status_reason(status: &LinkStatus) -> &str {
+    match status {
+        LinkStatus::Broken(reason) | LinkStatus::PolicyViolation(reason) => reason,
+        LinkStatus::Ok | LinkStatus::AccessDenied | LinkStatus::Recursive | LinkStatus::Cyclic => "",
+    }
+}
+
+fn status_from_tag(tag: u8, reason: String) -> LinkStatus {
+    match tag {
+        1 => LinkStatus::Broken(reason),
+        2 => LinkStatus::AccessDenied,
+        3 => LinkStatus::Recursive,
+        4 => LinkStatus::PolicyViolation(reason),
+        5 => LinkStatus::Cyclic,
+        _ => LinkStatus::Ok,
+    }
+}
+
+/// Serializes the cache as a flat little-endian binary blob: a fixed
+/// header, then the node table, then the entry table, each record
+/// length-prefixing its variable-width strings. No serde involved, so
+/// `load` can read it back with plain unaligned byte slicing instead of a
+/// schema-aware deserializer.
+pub(crate) fn save(
+    drive: &str,
+    usn_journal_id: u64,
+    next_usn: i64,
+    nodes: &HashMap<u64, FrnNode>,
+    entries: &HashMap<u64, CachedEntry>,
+) -> Result<(), String> {
+    let mut buffer = Vec::new();
+    buffer.extend_from_slice(&CACHE_MAGIC);
+    buffer.extend_from_slice(&CACHE_VERSION.to_le_bytes());
+    buffer.extend_from_slice(&usn_journal_id.to_le_bytes());
+    buffer.extend_from_slice(&next_usn.to_le_bytes());
+    buffer.extend_from_slice(&(nodes.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+
+    for (frn, node) in nodes {
+        buffer.extend_from_slice(&frn.to_le_bytes());
+        buffer.extend_from_slice(&node.parent_frn.to_le_bytes());
+        buffer.extend_from_slice(&node.file_attributes.to_le_bytes());
+
+        let name_bytes = node.name.as_bytes();
+        buffer.extend_from_slice(&(name_bytes.len() as u16).to_le_bytes());
+        buffer.extend_from_slice(name_bytes);
+    }
+
+    for (frn, entry) in entries {
+        buffer.extend_from_slice(&frn.to_le_bytes());
+        buffer.push(link_type_tag(&entry.link_type));
+        buffer.push(status_tag(&entry.status));
+        let reason_bytes = status_reason(&entry.status).as_bytes();
+        buffer.extend_from_slice(&(reason_bytes.len() as u32).to_le_bytes());
+        buffer.extend_from_slice(reason_bytes);
+
+        match entry.hardlink_key {
+            Some((volume_serial, file_index)) => {
+                buffer.push(1);
+                buffer.extend_from_slice(&volume_serial.to_le_bytes());
+                buffer.extend_from_slice(&file_index.to_le_bytes());
+            }
+            None => {
+                buffer.push(0);
+                buffer.extend_from_slice(&0_u32.to_le_bytes());
+                buffer.extend_from_slice(&0_u64.to_le_bytes());
+            }
+        }
+
+        let path_bytes = entry.path.as_bytes();
+        buffer.extend_from_slice(&(path_bytes.len() as u32).to_le_bytes());
+        buffer.extend_from_slice(path_bytes);
+
+        let target_bytes = entry.target.as_bytes();
+        buffer.extend_from_slice(&(target_bytes.len() as u32).to_le_bytes());
+        buffer.extend_from_slice(target_bytes);
+
+        buffer.extend_from_slice(&(entry.hardlink_siblings.len() as u32).to_le_bytes());
+        for sibling in &entry.hardlink_siblings {
+            let sibling_bytes = sibling.as_bytes();
+            buffer.extend_from_slice(&(sibling_bytes.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(sibling_bytes);
+        }
+    }
+
+    write_atomic(&cache_path(drive)?, &buffer)
+}
+
+fn read_u8(data: &[u8], offset: &mut usize) -> Option<u8> {
+    let value = *data.get(*offset)?;
+    *offset += 1;
+    Some(value)
+}
+
+fn read_u16(data: &[u8], offset: &mut usize) -> Option<u16> {
+    let end = offset.checked_add(2)?;
+    let bytes: [u8; 2] = data.get(*offset..end)?.try_into().ok()?;
+    *offset = end;
+    Some(u16::from_le_bytes(bytes))
+}
+
+fn read_u32(data: &[u8], offset: &mut usize) -> Option<u32> {
+    let end = offset.checked_add(4)?;
+    let bytes: [u8; 4] = data.get(*offset..end)?.try_into().ok()?;
+    *offset = end;
+    Some(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(data: &[u8], offset: &mut usize) -> Option<u64> {
+    let end = offset.checked_add(8)?;
+    let bytes: [u8; 8] = data.get(*offset..end)?.try_into().ok()?;
+    *offset = end;
+    Some(u64::from_le_bytes(bytes))
+}
+
+fn read_i64(data: &[u8], offset: &mut usize) -> Option<i64> {
+    read_u64(data, offset).map(|value| value as i64)
+}
+
+fn read_string(data: &[u8], offset: &mut usize, len: usize) -> Option<String> {
+    let end = offset.checked_add(len)?;
+    let bytes = data.get(*offset..end)?;
+    let value = String::from_utf8(bytes.to_vec()).ok()?;
+    *offset = end;
+    Some(value)
+}
+
+/// Memory-maps the cache file and parses it back into an `MftCache`.
+/// Returns `None` on any mismatch (missing file, bad magic/version,
+/// truncated record) rather than an error, since every caller treats a
+/// missing or unusable cache the same way: fall back to a full rescan.
+pub(crate) fn load(drive: &str) -> Option<MftCache> {
+    let path = cache_path(drive).ok()?;
+    let file = std::fs::File::open(&path).ok()?;
+    let mmap = unsafe { memmap2::Mmap::map(&file).ok()? };
+    let data: &[u8] = &mmap;
+
+    if data.len() < 28 || data[0..4] != CACHE_MAGIC {
+        return None;
+    }
+
+    let mut offset = 4;
+    let version = read_u32(data, &mut offset)?;
+    if version != CACHE_VERSION {
+        return None;
+    }
+
+    let usn_journal_id = read_u64(data, &mut offset)?;
+    let next_usn = read_i64(data, &mut offset)?;
+    let node_count = read_u32(data, &mut offset)? as usize;
+    let entry_count = read_u32(data, &mut offset)? as usize;
+
+    let mut nodes = HashMap::with_capacity(node_count);
+    for _ in 0..node_count {
+        let frn = read_u64(data, &mut offset)?;
+        let parent_frn = read_u64(data, &mut offset)?;
+        let file_attributes = read_u32(data, &mut offset)?;
+        let name_len = read_u16(data, &mut offset)? as usize;
+        let name = read_string(data, &mut offset, name_len)?;
+
+        nodes.insert(
+            frn,
+            FrnNode {
+                parent_frn,
+                name,
+                file_attributes,
+            },
+        );
+    }
+
+    let mut entries = HashMap::with_capacity(entry_count);
+    for _ in 0..entry_count {
+        let frn = read_u64(data, &mut offset)?;
+        let link_type = link_type_from_tag(read_u8(data, &mut offset)?);
+        let status_raw_tag = read_u8(data, &mut offset)?;
+        let reason_len = read_u32(data, &mut offset)? as usize;
+        let reason = read_string(data, &mut offset, reason_len)?;
+        let status = status_from_tag(status_raw_tag, reason);
+        let has_hardlink_key = read_u8(data, &mut offset)? == 1;
+        let volume_serial = read_u32(data, &mut offset)?;
+        let file_index = read_u64(data, &mut offset)?;
+        let hardlink_key = has_hardlink_key.then_some((volume_serial, file_index));
+        let path_len = read_u32(data, &mut offset)? as usize;
+        let path = read_string(data, &mut offset, path_len)?;
+        let target_len = read_u32(data, &mut offset)? as usize;
+        let target = read_string(data, &mut offset, target_len)?;
+
+        let sibling_count = read_u32(data, &mut offset)? as usize;
+        let mut hardlink_siblings = Vec::with_capacity(sibling_count);
+        for _ in 0..sibling_count {
+            let sibling_len = read_u32(data, &mut offset)? as usize;
+            hardlink_siblings.push(read_string(data, &mut offset, sibling_len)?);
+        }
+
+        entries.insert(
+            frn,
+            CachedEntry {
+                path,
+                target,
+                link_type,
+                status,
+                hardlink_key,
+                hardlink_siblings,
+            },
+        );
+    }
+
+    Some(MftCache {
+        usn_journal_id,
+        next_usn,
+        nodes,
+        entries,
+    })
+}
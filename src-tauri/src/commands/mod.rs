@@ -0,0 +1,16 @@
+pub mod archive;
+pub mod details;
+pub mod export;
+pub mod jobs;
+pub mod links;
+pub mod manifest;
+pub mod mft_cache;
+pub mod patterns;
+pub mod repair;
+pub mod scan;
+pub mod shell;
+pub mod sweep;
+pub mod validate;
+pub mod volumes;
+pub mod watch;
+pub mod work_jobs;
@@ -0,0 +1,216 @@
+//! Compiled include/exclude path patterns for scan filtering, modeled on
+//! pxar's match_pattern engine: `*` and `?` match within a single path
+//! segment, `**` matches zero or more whole segments, and a pattern
+//! anchored with a leading separator or drive letter must match from the
+//! start of the path instead of at any depth (the same distinction
+//! gitignore draws between `/build` and `build`).
+
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MatchKind {
+    Include,
+    Exclude,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MatchResult {
+    Include,
+    Exclude,
+    NoMatch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Glob(String),
+    DoubleStar,
+}
+
+#[derive(Debug, Clone)]
+struct Pattern {
+    anchored: bool,
+    segments: Vec<Segment>,
+}
+
+fn is_drive_anchored(normalized: &str) -> bool {
+    let bytes = normalized.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+impl Pattern {
+    fn compile(raw: &str) -> Option<Pattern> {
+        let normalized = raw.trim().replace('/', "\\");
+        if normalized.is_empty() {
+            return None;
+        }
+
+        let anchored = normalized.starts_with('\\') || is_drive_anchored(&normalized);
+        let lowered = normalized.to_lowercase();
+
+        let segments = lowered
+            .split('\\')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                if segment == "**" {
+                    Segment::DoubleStar
+                } else if segment.contains('*') || segment.contains('?') {
+                    Segment::Glob(segment.to_string())
+                } else {
+                    Segment::Literal(segment.to_string())
+                }
+            })
+            .collect();
+
+        Some(Pattern { anchored, segments })
+    }
+
+    fn matches(&self, path_segments: &[&str]) -> bool {
+        if self.anchored {
+            match_from(&self.segments, path_segments)
+        } else {
+            (0..=path_segments.len()).any(|start| match_from(&self.segments, &path_segments[start..]))
+        }
+    }
+}
+
+fn match_from(pattern: &[Segment], path: &[&str]) -> bool {
+    match pattern.first() {
+        // The pattern is fully consumed. Matching a directory also matches
+        // everything under it (the same rule gitignore uses for `/build`),
+        // so any leftover path segments still count as a match rather than
+        // requiring the pattern to account for every segment.
+        None => true,
+        Some(Segment::DoubleStar) => (0..=path.len()).any(|skip| match_from(&pattern[1..], &path[skip..])),
+        Some(segment) => match path.first() {
+            None => false,
+            Some(head) => segment_matches(segment, head) && match_from(&pattern[1..], &path[1..]),
+        },
+    }
+}
+
+fn segment_matches(segment: &Segment, text: &str) -> bool {
+    match segment {
+        Segment::Literal(value) => value == text,
+        Segment::Glob(pattern) => glob_match(pattern, text),
+        Segment::DoubleStar => unreachable!("** is consumed by match_from"),
+    }
+}
+
+/// Classic wildcard matcher: `*` matches any run of characters (including
+/// none), `?` matches exactly one. Both `pattern` and `text` are expected
+/// to already be lowercased, so the comparison is effectively
+/// case-insensitive.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    glob_match_inner(&pattern, &text)
+}
+
+fn glob_match_inner(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') => (0..=text.len()).any(|skip| glob_match_inner(&pattern[1..], &text[skip..])),
+        Some('?') => !text.is_empty() && glob_match_inner(&pattern[1..], &text[1..]),
+        Some(ch) => text.first() == Some(ch) && glob_match_inner(&pattern[1..], &text[1..]),
+    }
+}
+
+/// An ordered set of compiled exclude/include patterns evaluated with
+/// last-match-wins precedence: the result is whichever pattern, scanning
+/// front to back, matched most recently. Exclude patterns from
+/// `config.scan.excluded_paths` are compiled first, then include patterns
+/// from `config.scan.included_paths`, so an include re-admits a path an
+/// earlier exclude would otherwise have dropped (and a later exclude can
+/// still override an earlier include).
+#[derive(Clone)]
+pub(crate) struct PatternSet {
+    entries: Vec<(Pattern, MatchKind)>,
+}
+
+impl PatternSet {
+    pub(crate) fn compile(excluded: &[String], included: &[String]) -> PatternSet {
+        let mut entries = Vec::new();
+
+        for raw in excluded {
+            if let Some(pattern) = Pattern::compile(raw) {
+                entries.push((pattern, MatchKind::Exclude));
+            }
+        }
+
+        for raw in included {
+            if let Some(pattern) = Pattern::compile(raw) {
+                entries.push((pattern, MatchKind::Include));
+            }
+        }
+
+        PatternSet { entries }
+    }
+
+    pub(crate) fn evaluate(&self, path: &Path) -> MatchResult {
+        let text = path.to_string_lossy().replace('/', "\\").to_lowercase();
+        let segments: Vec<&str> = text.split('\\').filter(|segment| !segment.is_empty()).collect();
+
+        let mut result = MatchResult::NoMatch;
+
+        for (pattern, kind) in &self.entries {
+            if pattern.matches(&segments) {
+                result = match kind {
+                    MatchKind::Include => MatchResult::Include,
+                    MatchKind::Exclude => MatchResult::Exclude,
+                };
+            }
+        }
+
+        result
+    }
+
+    /// `true` only when the path's last matching pattern is an exclude;
+    /// an include, or no match at all, both mean "keep it".
+    pub(crate) fn is_excluded(&self, path: &Path) -> bool {
+        matches!(self.evaluate(path), MatchResult::Exclude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_prefix_still_excludes() {
+        let set = PatternSet::compile(&["C:\\Windows\\WinSxS".to_string()], &[]);
+        assert!(set.is_excluded(Path::new("C:\\Windows\\WinSxS\\nested\\file.dll")));
+        assert!(!set.is_excluded(Path::new("C:\\Windows\\System32\\file.dll")));
+    }
+
+    #[test]
+    fn double_star_matches_at_any_depth() {
+        let set = PatternSet::compile(&["**\\node_modules".to_string()], &[]);
+        assert!(set.is_excluded(Path::new("C:\\repo\\a\\b\\node_modules")));
+        assert!(set.is_excluded(Path::new("C:\\repo\\node_modules")));
+        assert!(!set.is_excluded(Path::new("C:\\repo\\node_modules_backup")));
+    }
+
+    #[test]
+    fn glob_segment_matches_extension() {
+        let set = PatternSet::compile(&["*.tmp".to_string()], &[]);
+        assert!(set.is_excluded(Path::new("C:\\repo\\cache.tmp")));
+        assert!(!set.is_excluded(Path::new("C:\\repo\\cache.tmp.bak")));
+    }
+
+    #[test]
+    fn include_overrides_earlier_exclude_by_order() {
+        let set = PatternSet::compile(
+            &["C:\\repo\\**".to_string()],
+            &["C:\\repo\\keep".to_string()],
+        );
+        assert!(set.is_excluded(Path::new("C:\\repo\\drop")));
+        assert_eq!(set.evaluate(Path::new("C:\\repo\\keep")), MatchResult::Include);
+    }
+
+    #[test]
+    fn case_and_separator_insensitive() {
+        let set = PatternSet::compile(&["c:/repo/Node_Modules".to_string()], &[]);
+        assert!(set.is_excluded(Path::new("C:\\REPO\\node_modules")));
+    }
+}
@@ -0,0 +1,153 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::details::classify_status;
+use crate::commands::export::write_atomic;
+use crate::commands::links::{create_link_internal, delete_link_internal};
+use crate::db::history::{log_action, ActionInput};
+use crate::types::{LinkEntry, LinkStatus};
+
+const REPORT_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LinkReport {
+    format_version: u32,
+    entries: Vec<LinkEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepairOutcome {
+    pub path: String,
+    pub action: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Serializes `entries` (statuses included) to a stable JSON report at
+/// `path` — the "dump" half of the check/dump/repair split. The report is
+/// a snapshot `repair_links` can later recreate dangling reparse points
+/// from if the links themselves go missing (e.g. a restore that skipped
+/// reparse points entirely).
+#[tauri::command]
+pub fn dump_links(entries: Vec<LinkEntry>, path: String) -> Result<(), String> {
+    let report = LinkReport {
+        format_version: REPORT_FORMAT_VERSION,
+        entries,
+    };
+
+    let serialized = serde_json::to_vec_pretty(&report)
+        .map_err(|e| format!("Failed to serialize link report: {e}"))?;
+
+    write_atomic(Path::new(&path), &serialized)
+}
+
+async fn repair_entry(entry: &LinkEntry) -> RepairOutcome {
+    let link_present = tokio::fs::symlink_metadata(&entry.path).await.is_ok();
+
+    if !link_present {
+        let target_is_dir = Path::new(&entry.target).is_dir();
+
+        return match create_link_internal(&entry.path, &entry.target, &entry.link_type, target_is_dir).await {
+            Ok(()) => RepairOutcome {
+                path: entry.path.clone(),
+                action: "recreated".to_string(),
+                success: true,
+                error: None,
+            },
+            Err(error) => RepairOutcome {
+                path: entry.path.clone(),
+                action: "recreate".to_string(),
+                success: false,
+                error: Some(error),
+            },
+        };
+    }
+
+    // The link file itself exists; only remove it if it's still dangling
+    // right now (the report may be stale — the target could have come back
+    // since it was dumped). The stored target can't be trusted enough to
+    // recreate against in that case, so the cleanest repair is deletion.
+    if !matches!(
+        classify_status(&entry.path),
+        LinkStatus::Broken(_) | LinkStatus::Recursive | LinkStatus::Cyclic
+    ) {
+        return RepairOutcome {
+            path: entry.path.clone(),
+            action: "skipped".to_string(),
+            success: true,
+            error: None,
+        };
+    }
+
+    match delete_link_internal(&entry.path).await {
+        Ok(()) => RepairOutcome {
+            path: entry.path.clone(),
+            action: "deleted".to_string(),
+            success: true,
+            error: None,
+        },
+        Err(error) => RepairOutcome {
+            path: entry.path.clone(),
+            action: "delete".to_string(),
+            success: false,
+            error: Some(error),
+        },
+    }
+}
+
+/// Reads a `dump_links` report and repairs every dangling entry it names:
+/// recreates the reparse point/hardlink when the link itself is missing,
+/// or deletes it when it's present but still resolves to nothing. Entries
+/// that are neither (already healthy, or no longer broken) are left alone.
+/// Every repair is logged through the same action history as manual
+/// create/delete operations, so it shows up in — and can be undone from —
+/// the normal history view.
+#[tauri::command]
+pub async fn repair_links(path: String) -> Result<Vec<RepairOutcome>, String> {
+    let content = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| format!("Failed to read link report {path}: {e}"))?;
+    let report: LinkReport =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse link report {path}: {e}"))?;
+
+    let conn = crate::db::open_connection()?;
+    let mut outcomes = Vec::with_capacity(report.entries.len());
+
+    for entry in &report.entries {
+        let outcome = repair_entry(entry).await;
+
+        if outcome.action != "skipped" {
+            log_action(
+                &conn,
+                ActionInput {
+                    action_type: if outcome.action.starts_with("recreate") {
+                        "Create".to_string()
+                    } else {
+                        "Delete".to_string()
+                    },
+                    link_path: entry.path.clone(),
+                    link_type: entry.link_type.clone(),
+                    target_old: if outcome.action.starts_with("recreate") {
+                        None
+                    } else {
+                        Some(entry.target.clone())
+                    },
+                    target_new: if outcome.action.starts_with("recreate") {
+                        Some(entry.target.clone())
+                    } else {
+                        None
+                    },
+                    success: outcome.success,
+                    error_msg: outcome.error.clone(),
+                },
+            )?;
+        }
+
+        outcomes.push(outcome);
+    }
+
+    Ok(outcomes)
+}
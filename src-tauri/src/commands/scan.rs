@@ -4,26 +4,37 @@ use std::fs;
 use std::mem::size_of;
 use std::os::windows::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
-use std::process::Command;
 
 use tauri::{AppHandle, Emitter};
-use walkdir::WalkDir;
-use windows_sys::Win32::Foundation::{CloseHandle, GetLastError, HANDLE, INVALID_HANDLE_VALUE, ERROR_HANDLE_EOF};
+use windows_sys::Win32::Foundation::{
+    CloseHandle, GetLastError, HANDLE, INVALID_HANDLE_VALUE, ERROR_HANDLE_EOF, ERROR_MORE_DATA,
+};
 use windows_sys::Win32::Storage::FileSystem::{
-    CreateFileW, GetFileInformationByHandle, BY_HANDLE_FILE_INFORMATION, FILE_ATTRIBUTE_DIRECTORY,
-    FILE_ATTRIBUTE_REPARSE_POINT, FILE_FLAG_BACKUP_SEMANTICS, FILE_FLAG_OPEN_REPARSE_POINT,
-    FILE_SHARE_DELETE, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
+    CreateFileW, FindClose, FindFirstFileNameW, FindNextFileNameW, GetFileInformationByHandle,
+    BY_HANDLE_FILE_INFORMATION, FILE_ATTRIBUTE_DIRECTORY, FILE_ATTRIBUTE_REPARSE_POINT,
+    FILE_FLAG_BACKUP_SEMANTICS, FILE_FLAG_OPEN_REPARSE_POINT, FILE_SHARE_DELETE, FILE_SHARE_READ,
+    FILE_SHARE_WRITE, OPEN_EXISTING,
 };
 use windows_sys::Win32::System::IO::DeviceIoControl;
-use windows_sys::Win32::System::Ioctl::{FSCTL_ENUM_USN_DATA, FSCTL_GET_REPARSE_POINT, FSCTL_QUERY_USN_JOURNAL};
+use windows_sys::Win32::System::Ioctl::{
+    FSCTL_ENUM_USN_DATA, FSCTL_GET_REPARSE_POINT, FSCTL_QUERY_USN_JOURNAL, FSCTL_READ_USN_JOURNAL,
+};
 
+use crate::commands::details::classify_status;
+use crate::commands::mft_cache::{self, CachedEntry};
+use crate::commands::patterns::PatternSet;
 use crate::config::load_config;
-use crate::types::{LinkEntry, LinkStatus, LinkType, ScanBatch, ScanMode, ScanProgress, ScanResult};
+use crate::types::{LinkEntry, LinkType, ScanBatch, ScanMode, ScanProgress, ScanResult};
 
 const GENERIC_READ_ACCESS: u32 = 0x8000_0000;
 const IO_REPARSE_TAG_MOUNT_POINT: u32 = 0xA0000003;
 const IO_REPARSE_TAG_SYMLINK: u32 = 0xA000000C;
-const SCAN_BATCH_SIZE: usize = 256;
+pub(crate) const SCAN_BATCH_SIZE: usize = 256;
+
+const USN_REASON_FILE_CREATE: u32 = 0x0000_0100;
+const USN_REASON_FILE_DELETE: u32 = 0x0000_0200;
+const USN_REASON_RENAME_OLD_NAME: u32 = 0x0000_1000;
+const USN_REASON_RENAME_NEW_NAME: u32 = 0x0000_2000;
 
 #[repr(C)]
 #[derive(Clone, Copy, Default)]
@@ -45,6 +56,17 @@ struct UsnJournalDataV0 {
     allocation_delta: u64,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct ReadUsnJournalDataV0 {
+    start_usn: i64,
+    reason_mask: u32,
+    return_only_on_close: u32,
+    timeout: u64,
+    bytes_to_wait_for: u64,
+    usn_journal_id: u64,
+}
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 struct UsnRecordV2Header {
@@ -64,10 +86,10 @@ struct UsnRecordV2Header {
 }
 
 #[derive(Clone)]
-struct FrnNode {
-    parent_frn: u64,
-    name: String,
-    file_attributes: u32,
+pub(crate) struct FrnNode {
+    pub(crate) parent_frn: u64,
+    pub(crate) name: String,
+    pub(crate) file_attributes: u32,
 }
 
 struct OwnedHandle(HANDLE);
@@ -120,7 +142,7 @@ fn parse_drive_letter(drive: &str) -> Result<char, String> {
     Ok(letter.to_ascii_uppercase())
 }
 
-fn normalize_drive(drive: &str) -> Result<String, String> {
+pub(crate) fn normalize_drive(drive: &str) -> Result<String, String> {
     let letter = parse_drive_letter(drive)?;
     Ok(format!("{letter}:\\"))
 }
@@ -133,7 +155,7 @@ fn normalize_path_for_prefix_compare(value: &str) -> String {
         .to_lowercase()
 }
 
-fn should_exclude(path: &Path, excluded: &[String]) -> bool {
+pub(crate) fn should_exclude(path: &Path, excluded: &[String]) -> bool {
     let path_text = normalize_path_for_prefix_compare(&path.to_string_lossy());
 
     excluded.iter().any(|item| {
@@ -163,7 +185,7 @@ fn emit_scan_batch(app: &AppHandle, batch: &mut Vec<LinkEntry>) {
     let _ = app.emit("scan:batch", payload);
 }
 
-fn map_symlink_type(path: &Path, target: &str) -> LinkType {
+pub(crate) fn map_symlink_type(path: &Path, target: &str) -> LinkType {
     let path_text = path.to_string_lossy().to_string();
     if let Ok(tag) = get_reparse_tag(&path_text) {
         return match tag {
@@ -188,37 +210,98 @@ fn map_symlink_type(path: &Path, target: &str) -> LinkType {
     }
 }
 
-fn find_hardlink_target(path: &Path) -> String {
+fn volume_root(path: &Path) -> String {
+    let text = path.to_string_lossy();
+    let mut chars = text.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(letter), Some(':')) if letter.is_ascii_alphabetic() => format!("{letter}:\\"),
+        _ => String::new(),
+    }
+}
+
+fn wide_to_string(buffer: &[u16], length: u32) -> String {
+    let len = (length as usize).min(buffer.len());
+    let slice = match buffer[..len].last() {
+        Some(0) => &buffer[..len - 1],
+        _ => &buffer[..len],
+    };
+    String::from_utf16_lossy(slice)
+}
+
+/// Enumerates every path name sharing `path`'s hardlink identity via
+/// `FindFirstFileNameW`/`FindNextFileNameW`, the native equivalent of
+/// `fsutil hardlink list` that doesn't spawn a process per call. Both
+/// functions return `ERROR_MORE_DATA` (and the required buffer length)
+/// when a name doesn't fit, so each call is retried once with a buffer
+/// grown to that length. The names returned are drive-relative (e.g.
+/// `\Users\foo\bar.txt`), so the volume root is prepended to match the
+/// absolute paths used elsewhere in the scanner. The result excludes
+/// `path` itself and is deduplicated, since the same name can otherwise
+/// surface twice across a grow-and-retry pair.
+pub(crate) fn find_hardlink_siblings(path: &Path) -> Vec<String> {
     let path_str = path.to_string_lossy().to_string();
+    let wide = to_wide_null(&path_str);
+    let root = volume_root(path);
+    let mut names: Vec<String> = Vec::new();
 
-    let output = Command::new("fsutil")
-        .args(["hardlink", "list", &path_str])
-        .output();
-
-    match output {
-        Ok(value) if value.status.success() => {
-            let stdout = String::from_utf8_lossy(&value.stdout).to_string();
-            let mut lines = stdout
-                .lines()
-                .map(str::trim)
-                .filter(|line| !line.is_empty())
-                .collect::<Vec<_>>();
-
-            if lines.is_empty() {
-                return path_str;
-            }
+    let mut buffer: Vec<u16> = vec![0; 260];
+    let mut length = buffer.len() as u32;
+    let mut handle = unsafe { FindFirstFileNameW(wide.as_ptr(), 0, &mut length, buffer.as_mut_ptr()) };
 
-            if let Some(candidate) = lines
-                .iter()
-                .find(|item| item.to_lowercase() != path_str.to_lowercase())
-            {
-                return (*candidate).to_string();
-            }
+    if handle == INVALID_HANDLE_VALUE && unsafe { GetLastError() } == ERROR_MORE_DATA {
+        buffer = vec![0; length as usize];
+        handle = unsafe { FindFirstFileNameW(wide.as_ptr(), 0, &mut length, buffer.as_mut_ptr()) };
+    }
+
+    if handle == INVALID_HANDLE_VALUE {
+        return names;
+    }
 
-            lines.remove(0).to_string()
+    names.push(wide_to_string(&buffer, length));
+
+    loop {
+        length = buffer.len() as u32;
+        let mut has_next = unsafe { FindNextFileNameW(handle, &mut length, buffer.as_mut_ptr()) };
+
+        if has_next == 0 && unsafe { GetLastError() } == ERROR_MORE_DATA {
+            buffer = vec![0; length as usize];
+            has_next = unsafe { FindNextFileNameW(handle, &mut length, buffer.as_mut_ptr()) };
         }
-        _ => path_str,
+
+        if has_next == 0 {
+            break;
+        }
+
+        names.push(wide_to_string(&buffer, length));
     }
+
+    unsafe { FindClose(handle) };
+
+    let path_lower = path_str.to_lowercase();
+    let mut siblings: Vec<String> = names
+        .into_iter()
+        .map(|name| format!("{root}{}", name.trim_start_matches(['\\', '/'])))
+        .collect();
+
+    siblings.sort();
+    siblings.dedup();
+    siblings.retain(|name| name.to_lowercase() != path_lower);
+    siblings
+}
+
+/// Splits `find_hardlink_siblings`'s result into the single display
+/// `target` `LinkEntry` has always shown (the first other name, or `path`
+/// itself when no sibling could be enumerated) and the full sibling list
+/// for `LinkEntry::hardlink_siblings`.
+pub(crate) fn describe_hardlink(path: &Path) -> (String, Vec<String>) {
+    let siblings = find_hardlink_siblings(path);
+    let target = siblings
+        .first()
+        .cloned()
+        .unwrap_or_else(|| path.to_string_lossy().to_string());
+
+    (target, siblings)
 }
 
 fn open_file_handle(path: &str, open_reparse_point: bool) -> Result<OwnedHandle, String> {
@@ -285,7 +368,7 @@ fn get_reparse_tag(path: &str) -> Result<u32, String> {
     Ok(u32::from_le_bytes(tag_bytes))
 }
 
-fn get_hardlink_info(path: &str) -> Result<(u32, u64, u32), String> {
+pub(crate) fn get_hardlink_info(path: &str) -> Result<(u32, u64, u32), String> {
     let handle = open_file_handle(path, false)?;
     let mut info: BY_HANDLE_FILE_INFORMATION = unsafe { std::mem::zeroed() };
 
@@ -467,14 +550,7 @@ fn open_volume_handle(drive: &str) -> Result<OwnedHandle, String> {
     Ok(owned)
 }
 
-fn scan_with_usn(drive: &str, app: &AppHandle) -> Result<Vec<LinkEntry>, String> {
-    if !crate::elevation::is_elevated() {
-        return Err("USN Journal requires elevated privileges".to_string());
-    }
-
-    let config = load_config()?;
-    let volume = open_volume_handle(drive)?;
-
+fn query_usn_journal(volume: &OwnedHandle) -> Result<UsnJournalDataV0, String> {
     let mut journal_data = UsnJournalDataV0::default();
     let mut bytes_returned = 0_u32;
 
@@ -492,16 +568,26 @@ fn scan_with_usn(drive: &str, app: &AppHandle) -> Result<Vec<LinkEntry>, String>
     };
 
     if ok == 0 {
-        return Err(format!(
-            "FSCTL_QUERY_USN_JOURNAL failed: {}",
-            unsafe { GetLastError() }
-        ));
+        return Err(format!("FSCTL_QUERY_USN_JOURNAL failed: {}", unsafe {
+            GetLastError()
+        }));
     }
 
+    Ok(journal_data)
+}
+
+/// Full `FSCTL_ENUM_USN_DATA` walk of the whole MFT, building the FRN
+/// topology map from scratch. This is the expensive path incremental
+/// rescans (`apply_usn_journal_delta`) exist to avoid.
+fn full_enum_usn_data(
+    volume: &OwnedHandle,
+    high_usn: i64,
+    app: &AppHandle,
+) -> Result<(HashMap<u64, FrnNode>, u64), String> {
     let mut mft_enum_data = MftEnumDataV0 {
         start_file_reference_number: 0,
         low_usn: 0,
-        high_usn: journal_data.next_usn,
+        high_usn,
     };
 
     let mut output_buffer = vec![0_u8; 1024 * 1024];
@@ -553,64 +639,295 @@ fn scan_with_usn(drive: &str, app: &AppHandle) -> Result<Vec<LinkEntry>, String>
         mft_enum_data.start_file_reference_number = next;
     }
 
-    let mut entries: Vec<LinkEntry> = Vec::new();
-    let mut cache: HashMap<u64, String> = HashMap::new();
-    let mut seen_hardlinks: HashSet<(u32, u64)> = HashSet::new();
-    let mut batch: Vec<LinkEntry> = Vec::with_capacity(SCAN_BATCH_SIZE);
-    let mut found = 0_u64;
-    let mut processed = 0_u64;
+    Ok((nodes, scanned))
+}
 
-    for (frn, node) in &nodes {
-        let path = match resolve_path_from_frn(*frn, drive, &nodes, &mut cache) {
-            Some(value) => value,
-            None => continue,
+/// Replays `FSCTL_READ_USN_JOURNAL` records starting at `start_usn`,
+/// patching `nodes` in place and recording every touched FRN in `touched`
+/// so the caller only has to re-resolve the paths that actually changed.
+/// `FILE_CREATE`/`RENAME_NEW_NAME` insert or update a node; `FILE_DELETE`/
+/// `RENAME_OLD_NAME` remove it.
+fn apply_usn_journal_delta(
+    volume: &OwnedHandle,
+    start_usn: i64,
+    usn_journal_id: u64,
+    nodes: &mut HashMap<u64, FrnNode>,
+    touched: &mut HashSet<u64>,
+    scanned: &mut u64,
+    app: &AppHandle,
+) -> Result<(), String> {
+    let mut read_data = ReadUsnJournalDataV0 {
+        start_usn,
+        reason_mask: 0xFFFF_FFFF,
+        return_only_on_close: 0,
+        timeout: 0,
+        bytes_to_wait_for: 0,
+        usn_journal_id,
+    };
+
+    let mut output_buffer = vec![0_u8; 256 * 1024];
+
+    loop {
+        let mut returned = 0_u32;
+
+        let ok = unsafe {
+            DeviceIoControl(
+                volume.0,
+                FSCTL_READ_USN_JOURNAL,
+                &mut read_data as *mut ReadUsnJournalDataV0 as *mut c_void,
+                size_of::<ReadUsnJournalDataV0>() as u32,
+                output_buffer.as_mut_ptr() as *mut c_void,
+                output_buffer.len() as u32,
+                &mut returned,
+                std::ptr::null_mut(),
+            )
         };
 
-        if should_exclude(Path::new(&path), &config.scan.excluded_paths) {
-            continue;
+        if ok == 0 {
+            let code = unsafe { GetLastError() };
+
+            if code == ERROR_HANDLE_EOF {
+                break;
+            }
+
+            return Err(format!("FSCTL_READ_USN_JOURNAL failed with error code {code}"));
         }
 
-        processed += 1;
+        let returned_usize = returned as usize;
+        if returned_usize <= size_of::<i64>() {
+            break;
+        }
 
-        if node.file_attributes & FILE_ATTRIBUTE_REPARSE_POINT != 0 {
-            let tag = get_reparse_tag(&path).unwrap_or_default();
-            let link_type = match tag {
-                IO_REPARSE_TAG_MOUNT_POINT => LinkType::Junction,
-                IO_REPARSE_TAG_SYMLINK => LinkType::Symlink,
-                _ => LinkType::Symlink,
-            };
+        let mut next_usn_bytes = [0_u8; 8];
+        next_usn_bytes.copy_from_slice(&output_buffer[0..8]);
+        let next_start_usn = i64::from_le_bytes(next_usn_bytes);
 
-            let target = fs::read_link(&path)
-                .map(|value| value.to_string_lossy().to_string())
-                .unwrap_or_default();
+        let mut offset = size_of::<i64>();
 
-            let entry = LinkEntry {
-                path: path.clone(),
-                target,
-                link_type,
-                status: LinkStatus::Ok,
+        while returned_usize.saturating_sub(offset) >= size_of::<UsnRecordV2Header>() {
+            let header_ptr = unsafe { output_buffer.as_ptr().add(offset) as *const UsnRecordV2Header };
+            let header = unsafe { std::ptr::read_unaligned(header_ptr) };
+
+            if header.record_length == 0 {
+                break;
+            }
+
+            let record_len = header.record_length as usize;
+            if record_len < size_of::<UsnRecordV2Header>() {
+                break;
+            }
+
+            let record_end = match offset.checked_add(record_len) {
+                Some(value) => value,
+                None => break,
             };
-            batch.push(entry.clone());
-            entries.push(entry);
 
-            found += 1;
-        } else if node.file_attributes & FILE_ATTRIBUTE_DIRECTORY == 0 {
-            if let Ok((volume_serial, file_index, links_count)) = get_hardlink_info(&path) {
-                if links_count > 1 && seen_hardlinks.insert((volume_serial, file_index)) {
-                    let entry = LinkEntry {
-                        path: path.clone(),
-                        target: find_hardlink_target(Path::new(&path)),
-                        link_type: LinkType::Hardlink,
-                        status: LinkStatus::Ok,
+            if record_end > returned_usize {
+                break;
+            }
+
+            if header.major_version == 2 {
+                let frn = header.file_reference_number;
+                let reason = header.reason;
+
+                if reason & (USN_REASON_FILE_DELETE | USN_REASON_RENAME_OLD_NAME) != 0 {
+                    nodes.remove(&frn);
+                    touched.insert(frn);
+                }
+
+                if reason & (USN_REASON_FILE_CREATE | USN_REASON_RENAME_NEW_NAME) != 0 {
+                    let name_start = match offset.checked_add(header.file_name_offset as usize) {
+                        Some(value) => value,
+                        None => break,
+                    };
+                    let name_len_bytes = header.file_name_length as usize;
+                    let name_end = match name_start.checked_add(name_len_bytes) {
+                        Some(value) => value,
+                        None => break,
                     };
-                    batch.push(entry.clone());
-                    entries.push(entry);
 
-                    found += 1;
+                    if name_end <= record_end && name_len_bytes % 2 == 0 {
+                        let name_len_u16 = name_len_bytes / 2;
+                        let name_ptr = unsafe { output_buffer.as_ptr().add(name_start) as *const u16 };
+                        let name_slice = unsafe { std::slice::from_raw_parts(name_ptr, name_len_u16) };
+                        let name = String::from_utf16_lossy(name_slice);
+
+                        nodes.insert(
+                            frn,
+                            FrnNode {
+                                parent_frn: header.parent_file_reference_number,
+                                name,
+                                file_attributes: header.file_attributes,
+                            },
+                        );
+                        touched.insert(frn);
+                    }
+                }
+
+                *scanned += 1;
+
+                if *scanned % 1000 == 0 {
+                    let _ = app.emit(
+                        "scan:progress",
+                        ScanProgress {
+                            scanned: *scanned,
+                            found: 0,
+                            current_path: "USN journal delta".to_string(),
+                        },
+                    );
                 }
             }
+
+            offset = record_end;
+        }
+
+        if next_start_usn == read_data.start_usn {
+            break;
+        }
+
+        read_data.start_usn = next_start_usn;
+    }
+
+    Ok(())
+}
+
+/// Resolves a touched reparse point or hardlink FRN into a `CachedEntry`,
+/// or `None` if the node no longer exists or isn't a link at all (a plain
+/// file/dir touched by the journal but not relevant to the scan).
+fn resolve_touched_entry(
+    frn: u64,
+    drive: &str,
+    nodes: &HashMap<u64, FrnNode>,
+    path_cache: &mut HashMap<u64, String>,
+    pattern_set: &PatternSet,
+    seen_hardlinks: &mut HashSet<(u32, u64)>,
+) -> Option<CachedEntry> {
+    let node = nodes.get(&frn)?;
+    let path = resolve_path_from_frn(frn, drive, nodes, path_cache)?;
+
+    if pattern_set.is_excluded(Path::new(&path)) {
+        return None;
+    }
+
+    if node.file_attributes & FILE_ATTRIBUTE_REPARSE_POINT != 0 {
+        let tag = get_reparse_tag(&path).unwrap_or_default();
+        let link_type = match tag {
+            IO_REPARSE_TAG_MOUNT_POINT => LinkType::Junction,
+            IO_REPARSE_TAG_SYMLINK => LinkType::Symlink,
+            _ => LinkType::Symlink,
+        };
+
+        let target = fs::read_link(&path)
+            .map(|value| value.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let status = classify_status(&path);
+
+        return Some(CachedEntry {
+            link_type,
+            path,
+            target,
+            status,
+            hardlink_key: None,
+            hardlink_siblings: Vec::new(),
+        });
+    }
+
+    if node.file_attributes & FILE_ATTRIBUTE_DIRECTORY == 0 {
+        if let Ok((volume_serial, file_index, links_count)) = get_hardlink_info(&path) {
+            if links_count > 1 && seen_hardlinks.insert((volume_serial, file_index)) {
+                let status = classify_status(&path);
+                let (target, hardlink_siblings) = describe_hardlink(Path::new(&path));
+                return Some(CachedEntry {
+                    link_type: LinkType::Hardlink,
+                    target,
+                    path,
+                    status,
+                    hardlink_key: Some((volume_serial, file_index)),
+                    hardlink_siblings,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Scans a volume's reparse points and hardlinks via the USN journal,
+/// reusing a persisted `MftCache` (see `crate::commands::mft_cache`) so
+/// repeat scans only replay the journal delta instead of re-walking the
+/// entire MFT with `FSCTL_ENUM_USN_DATA`. Falls back to a full enumeration
+/// whenever the cache is missing, belongs to a different journal instance
+/// (`usn_journal_id` mismatch, e.g. the journal was deleted and recreated),
+/// or its watermark predates `lowest_valid_usn` (the journal wrapped and
+/// the delta is no longer available).
+fn scan_with_usn(drive: &str, app: &AppHandle) -> Result<Vec<LinkEntry>, String> {
+    if !crate::elevation::is_elevated() {
+        return Err("USN Journal requires elevated privileges".to_string());
+    }
+
+    let config = load_config()?;
+    let pattern_set = PatternSet::compile(&config.scan.excluded_paths, &config.scan.included_paths);
+    let volume = open_volume_handle(drive)?;
+    let journal_data = query_usn_journal(&volume)?;
+
+    let cached = mft_cache::load(drive);
+    let can_resume = cached.as_ref().is_some_and(|cache| {
+        cache.usn_journal_id == journal_data.usn_journal_id && cache.next_usn >= journal_data.lowest_valid_usn
+    });
+
+    let mut scanned = 0_u64;
+    let (mut nodes, mut entries_by_frn, mut touched) = if can_resume {
+        let cache = cached.expect("checked by can_resume");
+        let mut nodes = cache.nodes;
+        let mut touched = HashSet::new();
+        apply_usn_journal_delta(
+            &volume,
+            cache.next_usn,
+            journal_data.usn_journal_id,
+            &mut nodes,
+            &mut touched,
+            &mut scanned,
+            app,
+        )?;
+        (nodes, cache.entries, touched)
+    } else {
+        let (nodes, full_scanned) = full_enum_usn_data(&volume, journal_data.next_usn, app)?;
+        scanned = full_scanned;
+        let touched: HashSet<u64> = nodes.keys().copied().collect();
+        (nodes, HashMap::new(), touched)
+    };
+
+    let mut path_cache: HashMap<u64, String> = HashMap::new();
+    let mut seen_hardlinks: HashSet<(u32, u64)> = entries_by_frn
+        .values()
+        .filter_map(|entry| entry.hardlink_key)
+        .collect();
+    let mut batch: Vec<LinkEntry> = Vec::with_capacity(SCAN_BATCH_SIZE);
+    let mut found = entries_by_frn.len() as u64;
+    let mut processed = 0_u64;
+
+    for frn in touched.drain() {
+        if let Some(removed) = entries_by_frn.remove(&frn) {
+            if let Some(hardlink_key) = removed.hardlink_key {
+                seen_hardlinks.remove(&hardlink_key);
+            }
+        }
+
+        if let Some(entry) = resolve_touched_entry(frn, drive, &nodes, &mut path_cache, &pattern_set, &mut seen_hardlinks) {
+            let link_entry = LinkEntry {
+                path: entry.path.clone(),
+                target: entry.target.clone(),
+                link_type: entry.link_type.clone(),
+                status: entry.status.clone(),
+                hardlink_siblings: entry.hardlink_siblings.clone(),
+            };
+            batch.push(link_entry);
+            found += 1;
+            entries_by_frn.insert(frn, entry);
         }
 
+        processed += 1;
+
         if batch.len() >= SCAN_BATCH_SIZE {
             emit_scan_batch(app, &mut batch);
         }
@@ -621,7 +938,7 @@ fn scan_with_usn(drive: &str, app: &AppHandle) -> Result<Vec<LinkEntry>, String>
                 ScanProgress {
                     scanned,
                     found,
-                    current_path: path,
+                    current_path: format!("FRN {frn}"),
                 },
             );
         }
@@ -629,12 +946,66 @@ fn scan_with_usn(drive: &str, app: &AppHandle) -> Result<Vec<LinkEntry>, String>
 
     emit_scan_batch(app, &mut batch);
 
+    if let Err(error) = mft_cache::save(drive, journal_data.usn_journal_id, journal_data.next_usn, &nodes, &entries_by_frn) {
+        let _ = app.emit("scan:progress", ScanProgress { scanned, found, current_path: format!("mft cache not saved: {error}") });
+    }
+
+    let entries: Vec<LinkEntry> = entries_by_frn
+        .values()
+        .map(|entry| LinkEntry {
+            path: entry.path.clone(),
+            target: entry.target.clone(),
+            link_type: entry.link_type.clone(),
+            status: entry.status.clone(),
+            hardlink_siblings: entry.hardlink_siblings.clone(),
+        })
+        .collect();
+
     Ok(entries)
 }
 
+/// Builds the walker used for the filesystem fallback scan. Honors
+/// `.gitignore` and a dedicated `.symviewignore` per directory (nearest
+/// ancestor wins, `!` negation supported, same as git's own precedence
+/// rules) plus any caller-supplied extra patterns, which are always
+/// treated as excludes regardless of a leading `!`. `pattern_set` additionally
+/// prunes whole directory subtrees the moment they're excluded, so a
+/// pattern like `**\node_modules` skips descending entirely instead of
+/// filtering out its contents one entry at a time; the tradeoff, same as
+/// gitignore, is that an include pattern can't resurrect a path nested
+/// under an already-pruned directory.
+fn build_ignore_walker(root_path: &Path, extra_ignore_patterns: &[String], pattern_set: &PatternSet) -> ignore::Walk {
+    let mut builder = ignore::WalkBuilder::new(root_path);
+    builder
+        .follow_links(false)
+        .hidden(false)
+        .git_ignore(true)
+        .git_global(false)
+        .git_exclude(false)
+        .require_git(false)
+        .add_custom_ignore_filename(".symviewignore");
+
+    if !extra_ignore_patterns.is_empty() {
+        let mut overrides = ignore::overrides::OverrideBuilder::new(root_path);
+        for pattern in extra_ignore_patterns {
+            let _ = overrides.add(&format!("!{pattern}"));
+        }
+
+        if let Ok(built) = overrides.build() {
+            builder.overrides(built);
+        }
+    }
+
+    let pattern_set = pattern_set.clone();
+    builder.filter_entry(move |entry| !pattern_set.is_excluded(entry.path()));
+
+    builder.build()
+}
+
 fn collect_walkdir_entries<F, B>(
     root_path: &Path,
-    excluded_paths: &[String],
+    pattern_set: &PatternSet,
+    extra_ignore_patterns: &[String],
     mut on_progress: F,
     mut on_batch: B,
 ) -> Vec<LinkEntry>
@@ -648,14 +1019,10 @@ where
     let mut seen_hardlinks: HashSet<(u32, u64)> = HashSet::new();
     let mut batch: Vec<LinkEntry> = Vec::with_capacity(SCAN_BATCH_SIZE);
 
-    for item in WalkDir::new(root_path)
-        .follow_links(false)
-        .into_iter()
-        .filter_map(Result::ok)
-    {
+    for item in build_ignore_walker(root_path, extra_ignore_patterns, pattern_set).filter_map(Result::ok) {
         let path = item.path().to_path_buf();
 
-        if should_exclude(&path, excluded_paths) {
+        if pattern_set.is_excluded(&path) {
             continue;
         }
 
@@ -673,12 +1040,15 @@ where
                 .map(|value| value.to_string_lossy().to_string())
                 .unwrap_or_default();
             let link_type = map_symlink_type(&path, &target);
+            let path_text = path.to_string_lossy().to_string();
+            let status = classify_status(&path_text);
 
             let entry = LinkEntry {
-                path: path.to_string_lossy().to_string(),
+                path: path_text,
                 target,
                 link_type,
-                status: LinkStatus::Ok,
+                status,
+                hardlink_siblings: Vec::new(),
             };
             batch.push(entry.clone());
             entries.push(entry);
@@ -689,11 +1059,14 @@ where
 
             if let Ok((volume_serial, file_index, links_count)) = get_hardlink_info(&path_text) {
                 if links_count > 1 && seen_hardlinks.insert((volume_serial, file_index)) {
+                    let status = classify_status(&path_text);
+                    let (target, hardlink_siblings) = describe_hardlink(&path);
                     let entry = LinkEntry {
                         path: path_text.clone(),
-                        target: find_hardlink_target(&path),
+                        target,
                         link_type: LinkType::Hardlink,
-                        status: LinkStatus::Ok,
+                        status,
+                        hardlink_siblings,
                     };
                     batch.push(entry.clone());
                     entries.push(entry);
@@ -719,7 +1092,10 @@ where
     entries
 }
 
-#[allow(dead_code)]
+/// Runs the walkdir fallback scan against an arbitrary path instead of a
+/// drive letter. Used by integration tests and by the bench harness (see
+/// `crate::bench`), which both want to scan a synthetic directory rather
+/// than a real volume.
 pub fn scan_path_with_walkdir_for_tests(path: &str) -> Result<Vec<LinkEntry>, String> {
     let root_path = PathBuf::from(path);
 
@@ -729,13 +1105,18 @@ pub fn scan_path_with_walkdir_for_tests(path: &str) -> Result<Vec<LinkEntry>, St
 
     Ok(collect_walkdir_entries(
         &root_path,
+        &PatternSet::compile(&[], &[]),
         &[],
         |_scanned, _found, _current_path| {},
         |_batch| {},
     ))
 }
 
-fn scan_with_walkdir(drive: &str, app: &AppHandle) -> Result<Vec<LinkEntry>, String> {
+fn scan_with_walkdir(
+    drive: &str,
+    app: &AppHandle,
+    extra_ignore_patterns: &[String],
+) -> Result<Vec<LinkEntry>, String> {
     let config = load_config()?;
     let root = normalize_drive(drive)?;
     let root_path = PathBuf::from(&root);
@@ -744,9 +1125,11 @@ fn scan_with_walkdir(drive: &str, app: &AppHandle) -> Result<Vec<LinkEntry>, Str
         return Err(format!("Volume path does not exist: {root}"));
     }
 
+    let pattern_set = PatternSet::compile(&config.scan.excluded_paths, &config.scan.included_paths);
     let entries = collect_walkdir_entries(
         &root_path,
-        &config.scan.excluded_paths,
+        &pattern_set,
+        extra_ignore_patterns,
         |scanned, found, current_path| {
             let _ = app.emit(
                 "scan:progress",
@@ -765,8 +1148,51 @@ fn scan_with_walkdir(drive: &str, app: &AppHandle) -> Result<Vec<LinkEntry>, Str
     Ok(entries)
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeScanOutcome {
+    pub letter: String,
+    pub result: Option<ScanResult>,
+    pub error: Option<String>,
+}
+
+/// Scans every NTFS volume `list_volumes` reports, reusing the same
+/// USN-first/walkdir-fallback path as a single-drive scan so a failure on
+/// one volume (e.g. missing volume-read rights) doesn't abort the rest.
 #[tauri::command]
-pub async fn scan_volume(drive: String, app: AppHandle) -> Result<ScanResult, String> {
+pub async fn scan_all_volumes(
+    app: AppHandle,
+    extra_ignore_patterns: Option<Vec<String>>,
+) -> Result<Vec<VolumeScanOutcome>, String> {
+    let volumes = crate::commands::volumes::list_volumes()?;
+    let mut outcomes = Vec::with_capacity(volumes.len());
+
+    for volume in volumes {
+        let letter = volume.letter;
+        let outcome = match scan_volume(letter.clone(), app.clone(), extra_ignore_patterns.clone()).await {
+            Ok(result) => VolumeScanOutcome {
+                letter,
+                result: Some(result),
+                error: None,
+            },
+            Err(error) => VolumeScanOutcome {
+                letter,
+                result: None,
+                error: Some(error),
+            },
+        };
+        outcomes.push(outcome);
+    }
+
+    Ok(outcomes)
+}
+
+#[tauri::command]
+pub async fn scan_volume(
+    drive: String,
+    app: AppHandle,
+    extra_ignore_patterns: Option<Vec<String>>,
+) -> Result<ScanResult, String> {
     let normalized_drive = normalize_drive(&drive)?;
     let drive_for_scan = normalized_drive.clone();
     let app_for_scan = app.clone();
@@ -782,9 +1208,12 @@ pub async fn scan_volume(drive: String, app: AppHandle) -> Result<ScanResult, St
         }),
         Err(_) => {
             let drive_fallback = normalized_drive;
-            let entries = tokio::task::spawn_blocking(move || scan_with_walkdir(&drive_fallback, &app))
-                .await
-                .map_err(|e| format!("walkdir task join error: {e}"))??;
+            let patterns = extra_ignore_patterns.unwrap_or_default();
+            let entries = tokio::task::spawn_blocking(move || {
+                scan_with_walkdir(&drive_fallback, &app, &patterns)
+            })
+            .await
+            .map_err(|e| format!("walkdir task join error: {e}"))??;
 
             Ok(ScanResult {
                 entries,
@@ -796,9 +1225,71 @@ pub async fn scan_volume(drive: String, app: AppHandle) -> Result<ScanResult, St
 
 #[cfg(test)]
 mod tests {
-    use super::{normalize_drive, should_exclude};
+    use super::{build_ignore_walker, normalize_drive, should_exclude};
+    use crate::commands::patterns::PatternSet;
     use std::path::Path;
 
+    fn walked_file_names(root: &Path, extra_ignore_patterns: &[String]) -> Vec<String> {
+        let pattern_set = PatternSet::compile(&[], &[]);
+        build_ignore_walker(root, extra_ignore_patterns, &pattern_set)
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().map(|ft| ft.is_file()).unwrap_or(false))
+            .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+            .collect()
+    }
+
+    #[test]
+    fn symviewignore_prunes_matching_directories() {
+        let root = std::env::temp_dir().join(format!("symview-scan-ignore-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("node_modules")).unwrap();
+        std::fs::write(root.join("node_modules").join("pkg.json"), b"{}").unwrap();
+        std::fs::write(root.join("keep.txt"), b"keep").unwrap();
+        std::fs::write(root.join(".symviewignore"), b"node_modules/\n").unwrap();
+
+        let names = walked_file_names(&root, &[]);
+        assert!(names.contains(&"keep.txt".to_string()));
+        assert!(!names.contains(&"pkg.json".to_string()));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn extra_ignore_patterns_exclude_matching_files() {
+        let root = std::env::temp_dir().join(format!("symview-scan-extra-ignore-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("keep.txt"), b"keep").unwrap();
+        std::fs::write(root.join("drop.log"), b"drop").unwrap();
+
+        let names = walked_file_names(&root, &["*.log".to_string()]);
+        assert!(names.contains(&"keep.txt".to_string()));
+        assert!(!names.contains(&"drop.log".to_string()));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn glob_pattern_prunes_matching_directories() {
+        let root = std::env::temp_dir().join(format!("symview-scan-glob-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("node_modules")).unwrap();
+        std::fs::write(root.join("node_modules").join("pkg.json"), b"{}").unwrap();
+        std::fs::write(root.join("keep.txt"), b"keep").unwrap();
+
+        let pattern_set = PatternSet::compile(&["**\\node_modules".to_string()], &[]);
+        let names: Vec<String> = build_ignore_walker(&root, &[], &pattern_set)
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().map(|ft| ft.is_file()).unwrap_or(false))
+            .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+            .collect();
+
+        assert!(names.contains(&"keep.txt".to_string()));
+        assert!(!names.contains(&"pkg.json".to_string()));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
     #[test]
     fn normalize_drive_accepts_expected_forms() {
         assert_eq!(normalize_drive("c:").unwrap(), "C:\\");
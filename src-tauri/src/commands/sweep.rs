@@ -0,0 +1,189 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use walkdir::WalkDir;
+
+use crate::commands::details::classify_status;
+use crate::commands::links::delete_link_internal;
+use crate::db::history::{log_action, ActionInput};
+use crate::types::{LinkStatus, LinkType};
+
+const SWEEP_PROGRESS_INTERVAL: usize = 200;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum DeleteMethod {
+    None,
+    Delete,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SweepProgress {
+    checked: usize,
+    invalid: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SweepRemoval {
+    pub path: String,
+    pub removed: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SweepSummary {
+    pub invalid: usize,
+    pub removed: usize,
+    pub failed: usize,
+    pub removals: Vec<SweepRemoval>,
+}
+
+fn detect_link_type(path: &Path) -> LinkType {
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.is_dir() => LinkType::Junction,
+        _ => LinkType::Symlink,
+    }
+}
+
+fn collect_candidate_links(root: &Path) -> Vec<PathBuf> {
+    WalkDir::new(root)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            std::fs::symlink_metadata(entry.path())
+                .map(|metadata| metadata.file_type().is_symlink())
+                .unwrap_or(false)
+        })
+        .map(|entry| entry.path().to_path_buf())
+        .collect()
+}
+
+fn classify_candidates(app: &AppHandle, candidates: Vec<PathBuf>) -> Vec<PathBuf> {
+    let checked = Arc::new(AtomicUsize::new(0));
+    let invalid = Arc::new(AtomicUsize::new(0));
+
+    let broken: Vec<PathBuf> = candidates
+        .par_iter()
+        .filter_map(|path| {
+            let status = classify_status(&path.to_string_lossy());
+            let checked_so_far = checked.fetch_add(1, Ordering::SeqCst) + 1;
+            let is_broken = matches!(status, LinkStatus::Broken(_) | LinkStatus::Recursive | LinkStatus::Cyclic);
+
+            if is_broken {
+                invalid.fetch_add(1, Ordering::SeqCst);
+            }
+
+            if checked_so_far % SWEEP_PROGRESS_INTERVAL == 0 {
+                let _ = app.emit(
+                    "sweep:progress",
+                    SweepProgress {
+                        checked: checked_so_far,
+                        invalid: invalid.load(Ordering::SeqCst),
+                    },
+                );
+            }
+
+            is_broken.then(|| path.clone())
+        })
+        .collect();
+
+    let _ = app.emit(
+        "sweep:progress",
+        SweepProgress {
+            checked: checked.load(Ordering::SeqCst),
+            invalid: invalid.load(Ordering::SeqCst),
+        },
+    );
+
+    broken
+}
+
+/// Scans `root` for links in parallel via rayon, classifying each through the
+/// same cycle-safe `classify_status` used by `validate_links`, then (when
+/// `delete_method` is `Delete`) removes every broken or recursive link and
+/// records the removal in the history DB.
+#[tauri::command]
+pub async fn sweep_broken_links(
+    app: AppHandle,
+    root: String,
+    delete_method: DeleteMethod,
+) -> Result<SweepSummary, String> {
+    let root_path = PathBuf::from(&root);
+    if !root_path.exists() {
+        return Err(format!("Path does not exist: {root}"));
+    }
+
+    let app_for_scan = app.clone();
+    let broken = tokio::task::spawn_blocking(move || {
+        let candidates = collect_candidate_links(&root_path);
+        classify_candidates(&app_for_scan, candidates)
+    })
+    .await
+    .map_err(|e| format!("Sweep task join error: {e}"))?;
+
+    let invalid = broken.len();
+
+    if matches!(delete_method, DeleteMethod::None) {
+        return Ok(SweepSummary {
+            invalid,
+            removed: 0,
+            failed: 0,
+            removals: Vec::new(),
+        });
+    }
+
+    let conn = crate::db::open_connection()?;
+    let mut removals = Vec::with_capacity(broken.len());
+    let mut removed = 0_usize;
+    let mut failed = 0_usize;
+
+    for path in broken {
+        let path_text = path.to_string_lossy().to_string();
+        let link_type = detect_link_type(&path);
+
+        let operation = delete_link_internal(&path_text).await;
+        let (success, error_msg) = match &operation {
+            Ok(_) => (true, None),
+            Err(error) => (false, Some(error.clone())),
+        };
+
+        log_action(
+            &conn,
+            ActionInput {
+                action_type: "Delete".to_string(),
+                link_path: path_text.clone(),
+                link_type,
+                target_old: None,
+                target_new: None,
+                success,
+                error_msg: error_msg.clone(),
+            },
+        )?;
+
+        if success {
+            removed += 1;
+        } else {
+            failed += 1;
+        }
+
+        removals.push(SweepRemoval {
+            path: path_text,
+            removed: success,
+            error: error_msg,
+        });
+    }
+
+    Ok(SweepSummary {
+        invalid,
+        removed,
+        failed,
+        removals,
+    })
+}
@@ -1,33 +1,27 @@
 use std::collections::HashMap;
-use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use tokio::task::JoinSet;
 
+use crate::commands::details::classify_status_with_target;
+use crate::policy::evaluate_policy;
 use crate::types::{LinkEntry, LinkStatus};
 
-fn resolve_target(link_path: &str, target: &str) -> PathBuf {
-    let target_path = PathBuf::from(target);
-
-    if target_path.is_absolute() {
-        target_path
-    } else {
-        Path::new(link_path)
-            .parent()
-            .unwrap_or_else(|| Path::new(""))
-            .join(target_path)
-    }
-}
-
-fn classify_error(error: std::io::Error) -> LinkStatus {
-    match error.kind() {
-        std::io::ErrorKind::NotFound => LinkStatus::Broken("target does not exist".to_string()),
-        std::io::ErrorKind::PermissionDenied => LinkStatus::AccessDenied,
-        _ => LinkStatus::Broken(error.to_string()),
+/// Classifies `entry`'s target and, if a policy script is configured, runs
+/// it against the result. Both steps happen inside the same `spawn_blocking`
+/// call under one 500ms budget, so a runaway policy script can't hang the
+/// validation loop any more than a slow filesystem hop already could.
+fn classify_and_apply_policy(entry: &LinkEntry) -> LinkStatus {
+    let (status, resolved_target) = classify_status_with_target(&entry.path);
+
+    match evaluate_policy(entry, &resolved_target, &status) {
+        Ok(Some(reason)) => LinkStatus::PolicyViolation(reason),
+        Ok(None) => status,
+        Err(error) => LinkStatus::Broken(format!("policy script failed: {error}")),
     }
 }
 
-async fn validate_one(entry: LinkEntry) -> LinkEntry {
+pub(crate) async fn validate_one(entry: LinkEntry) -> LinkEntry {
     if entry.target.trim().is_empty() {
         return LinkEntry {
             status: LinkStatus::Broken("target path is empty".to_string()),
@@ -35,20 +29,18 @@ async fn validate_one(entry: LinkEntry) -> LinkEntry {
         };
     }
 
-    let path = resolve_target(&entry.path, &entry.target);
-
     let check = tokio::time::timeout(
         Duration::from_millis(500),
-        tokio::task::spawn_blocking(move || std::fs::metadata(path)),
+        tokio::task::spawn_blocking({
+            let entry = entry.clone();
+            move || classify_and_apply_policy(&entry)
+        }),
     )
     .await;
 
     let status = match check {
         Ok(joined) => match joined {
-            Ok(result) => match result {
-                Ok(_) => LinkStatus::Ok,
-                Err(error) => classify_error(error),
-            },
+            Ok(status) => status,
             Err(error) => LinkStatus::Broken(format!("validation join error: {error}")),
         },
         Err(_) => LinkStatus::Broken("timeout resolving target".to_string()),
@@ -57,15 +49,20 @@ async fn validate_one(entry: LinkEntry) -> LinkEntry {
     LinkEntry { status, ..entry }
 }
 
-#[tauri::command]
-pub async fn validate_links(entries: Vec<LinkEntry>) -> Vec<LinkEntry> {
+pub(crate) const VALIDATE_LINKS_CONCURRENCY: usize = 16;
+
+/// Validates `entries` across `concurrency` concurrent workers. Factored out
+/// of the `validate_links` command so the bench harness (see
+/// `crate::bench`) can sweep worker counts instead of being stuck with
+/// `VALIDATE_LINKS_CONCURRENCY` forever.
+pub(crate) async fn validate_links_with_concurrency(entries: Vec<LinkEntry>, concurrency: usize) -> Vec<LinkEntry> {
     let mut pending: Vec<LinkEntry> = entries;
     let mut join_set = JoinSet::new();
     let mut validated: Vec<LinkEntry> = Vec::new();
     let mut inflight: HashMap<tokio::task::Id, LinkEntry> = HashMap::new();
 
     loop {
-        while join_set.len() < 16 {
+        while join_set.len() < concurrency.max(1) {
             if let Some(entry) = pending.pop() {
                 let fallback = entry.clone();
                 let task = join_set.spawn(validate_one(entry));
@@ -95,6 +92,7 @@ pub async fn validate_links(entries: Vec<LinkEntry>) -> Vec<LinkEntry> {
                             target: "".to_string(),
                             link_type: crate::types::LinkType::Symlink,
                             status: LinkStatus::Broken(format!("validation worker crashed: {error}")),
+                            hardlink_siblings: Vec::new(),
                         });
                     }
                 }
@@ -106,6 +104,11 @@ pub async fn validate_links(entries: Vec<LinkEntry>) -> Vec<LinkEntry> {
     validated
 }
 
+#[tauri::command]
+pub async fn validate_links(entries: Vec<LinkEntry>) -> Vec<LinkEntry> {
+    validate_links_with_concurrency(entries, VALIDATE_LINKS_CONCURRENCY).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,15 +120,58 @@ mod tests {
             target: "C:\\definitely_missing_target_123".to_string(),
             link_type: crate::types::LinkType::Symlink,
             status: LinkStatus::Ok,
+            hardlink_siblings: Vec::new(),
         };
 
         let validated = validate_one(entry).await;
 
         match validated.status {
-            LinkStatus::Broken(_) | LinkStatus::AccessDenied | LinkStatus::Ok => {}
+            LinkStatus::Broken(_)
+            | LinkStatus::AccessDenied
+            | LinkStatus::Ok
+            | LinkStatus::Recursive
+            | LinkStatus::Cyclic
+            | LinkStatus::PolicyViolation(_) => {}
         }
     }
 
+    #[tokio::test]
+    async fn classifies_cyclic_chain() {
+        let dir = std::env::temp_dir().join(format!(
+            "symview-validate-cycle-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let a = dir.join("a.lnk");
+        let b = dir.join("b.lnk");
+
+        #[cfg(windows)]
+        {
+            std::os::windows::fs::symlink_file(&b, &a).unwrap();
+            std::os::windows::fs::symlink_file(&a, &b).unwrap();
+        }
+        #[cfg(not(windows))]
+        {
+            std::os::unix::fs::symlink(&b, &a).unwrap();
+            std::os::unix::fs::symlink(&a, &b).unwrap();
+        }
+
+        let entry = LinkEntry {
+            path: a.to_string_lossy().to_string(),
+            target: b.to_string_lossy().to_string(),
+            link_type: crate::types::LinkType::Symlink,
+            status: LinkStatus::Ok,
+            hardlink_siblings: Vec::new(),
+        };
+
+        let validated = validate_one(entry).await;
+        assert!(matches!(validated.status, LinkStatus::Cyclic));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[tokio::test]
     async fn empty_target_is_broken() {
         let entry = LinkEntry {
@@ -133,6 +179,7 @@ mod tests {
             target: "".to_string(),
             link_type: crate::types::LinkType::Symlink,
             status: LinkStatus::Ok,
+            hardlink_siblings: Vec::new(),
         };
 
         let validated = validate_one(entry).await;
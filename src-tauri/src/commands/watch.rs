@@ -0,0 +1,256 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+
+use crate::commands::details::resolve_target;
+use crate::commands::validate::validate_one;
+use crate::types::LinkEntry;
+
+const WATCH_DEBOUNCE_MILLIS: u64 = 300;
+
+fn watch_controls() -> &'static Mutex<HashMap<String, Arc<AtomicBool>>> {
+    static CONTROLS: OnceLock<Mutex<HashMap<String, Arc<AtomicBool>>>> = OnceLock::new();
+    CONTROLS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn is_relevant_kind(kind: &EventKind) -> bool {
+    matches!(
+        kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) | EventKind::Any
+    )
+}
+
+/// Watched dirs cover both a link's own parent directory and its resolved
+/// target's parent directory, so `affected_paths` needs to map an event on
+/// either back to the `LinkEntry` it belongs to: `target_owners` is that
+/// reverse index, keyed by resolved target path.
+fn affected_paths(
+    event: &Event,
+    tracked: &HashMap<String, LinkEntry>,
+    target_owners: &HashMap<String, Vec<String>>,
+) -> Vec<String> {
+    let mut affected: HashSet<String> = HashSet::new();
+
+    for path in event.paths.iter().filter_map(|path| path.to_str()) {
+        if tracked.contains_key(path) {
+            affected.insert(path.to_string());
+        }
+
+        if let Some(owners) = target_owners.get(path) {
+            affected.extend(owners.iter().cloned());
+        }
+    }
+
+    affected.into_iter().collect()
+}
+
+/// Resolves each entry's target to an absolute path and groups entries by
+/// it, so a filesystem event on a shared target maps back to every link
+/// that points at it (reusing `resolve_target`'s resolution rules, the same
+/// ones `get_link_details` relies on for `target_real`).
+fn index_targets(tracked: &HashMap<String, LinkEntry>) -> HashMap<String, Vec<String>> {
+    let mut owners: HashMap<String, Vec<String>> = HashMap::new();
+
+    for entry in tracked.values() {
+        let resolved_target = resolve_target(&entry.path, &entry.target);
+        owners.entry(resolved_target).or_default().push(entry.path.clone());
+    }
+
+    owners
+}
+
+async fn revalidate_changed(
+    watch_id: &str,
+    paths: &[String],
+    tracked: &Arc<Mutex<HashMap<String, LinkEntry>>>,
+    app: &AppHandle,
+) {
+    for path in paths {
+        let entry = {
+            let guard = tracked.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            guard.get(path).cloned()
+        };
+
+        let Some(entry) = entry else { continue };
+        let validated = validate_one(entry).await;
+
+        {
+            let mut guard = tracked.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            guard.insert(path.clone(), validated.clone());
+        }
+
+        let _ = app.emit(
+            "watch:status-changed",
+            serde_json::json!({
+                "watchId": watch_id,
+                "path": validated.path,
+                "status": validated.status,
+            }),
+        );
+    }
+}
+
+fn run_watch(watch_id: String, entries: Vec<LinkEntry>, app: AppHandle, stop: Arc<AtomicBool>) {
+    let tracked: Arc<Mutex<HashMap<String, LinkEntry>>> = Arc::new(Mutex::new(
+        entries.into_iter().map(|entry| (entry.path.clone(), entry)).collect(),
+    ));
+
+    let (watched_dirs, target_owners): (HashSet<PathBuf>, HashMap<String, Vec<String>>) = {
+        let guard = tracked.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let target_owners = index_targets(&guard);
+
+        let mut dirs: HashSet<PathBuf> = guard
+            .keys()
+            .filter_map(|path| Path::new(path).parent().map(Path::to_path_buf))
+            .collect();
+        dirs.extend(target_owners.keys().filter_map(|path| Path::new(path).parent().map(Path::to_path_buf)));
+
+        (dirs, target_owners)
+    };
+
+    let (tx, rx) = std::sync::mpsc::channel::<notify::Result<Event>>();
+
+    let mut watcher = match RecommendedWatcher::new(tx, notify::Config::default()) {
+        Ok(value) => value,
+        Err(_) => return,
+    };
+
+    for dir in &watched_dirs {
+        let _ = watcher.watch(dir, RecursiveMode::NonRecursive);
+    }
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(value) => value,
+        Err(_) => return,
+    };
+
+    let mut pending: HashSet<String> = HashSet::new();
+    let mut last_event = Instant::now();
+
+    loop {
+        if stop.load(Ordering::SeqCst) {
+            return;
+        }
+
+        match rx.recv_timeout(Duration::from_millis(50)) {
+            Ok(Ok(event)) if is_relevant_kind(&event.kind) => {
+                let affected = {
+                    let guard = tracked.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                    affected_paths(&event, &guard, &target_owners)
+                };
+
+                if !affected.is_empty() {
+                    pending.extend(affected);
+                    last_event = Instant::now();
+                }
+            }
+            Ok(_) => {}
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+
+        if !pending.is_empty() && last_event.elapsed() >= Duration::from_millis(WATCH_DEBOUNCE_MILLIS) {
+            let to_check: Vec<String> = pending.drain().collect();
+            runtime.block_on(revalidate_changed(&watch_id, &to_check, &tracked, &app));
+        }
+    }
+}
+
+/// Watches the parent directories of `entries` *and* of their resolved
+/// targets, and re-validates only the affected links when create/modify/
+/// remove/rename events settle for `WATCH_DEBOUNCE_MILLIS`, instead of
+/// forcing a full rescan for a single changed link or target — e.g.
+/// deleting a link's target flips it to `Broken` live even though the
+/// target lives in a different, unwatched-by-default directory.
+#[tauri::command]
+pub fn start_watch(app: AppHandle, entries: Vec<LinkEntry>) -> Result<String, String> {
+    let watch_id = Uuid::new_v4().to_string();
+    let stop = Arc::new(AtomicBool::new(false));
+
+    watch_controls()
+        .lock()
+        .map_err(|_| "Watch registry lock poisoned".to_string())?
+        .insert(watch_id.clone(), stop.clone());
+
+    let thread_watch_id = watch_id.clone();
+    std::thread::spawn(move || run_watch(thread_watch_id, entries, app, stop));
+
+    Ok(watch_id)
+}
+
+#[tauri::command]
+pub fn stop_watch(watch_id: String) -> Result<(), String> {
+    let mut controls = watch_controls()
+        .lock()
+        .map_err(|_| "Watch registry lock poisoned".to_string())?;
+
+    let stop = controls
+        .remove(&watch_id)
+        .ok_or_else(|| format!("No active watch with id {watch_id}"))?;
+
+    stop.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{LinkStatus, LinkType};
+
+    fn sample_entry(path: &str, target: &str) -> LinkEntry {
+        LinkEntry {
+            path: path.to_string(),
+            target: target.to_string(),
+            link_type: LinkType::Symlink,
+            status: LinkStatus::Ok,
+            hardlink_siblings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn affected_paths_only_includes_tracked_links() {
+        let mut tracked = HashMap::new();
+        tracked.insert(
+            "C:\\tmp\\a.lnk".to_string(),
+            sample_entry("C:\\tmp\\a.lnk", "C:\\tmp\\target"),
+        );
+
+        let event = Event::new(EventKind::Modify(notify::event::ModifyKind::Any))
+            .add_path(PathBuf::from("C:\\tmp\\a.lnk"))
+            .add_path(PathBuf::from("C:\\tmp\\unwatched.lnk"));
+
+        let affected = affected_paths(&event, &tracked, &HashMap::new());
+        assert_eq!(affected, vec!["C:\\tmp\\a.lnk".to_string()]);
+    }
+
+    #[test]
+    fn affected_paths_maps_a_target_event_back_to_its_link() {
+        let mut tracked = HashMap::new();
+        tracked.insert(
+            "C:\\tmp\\a.lnk".to_string(),
+            sample_entry("C:\\tmp\\a.lnk", "C:\\tmp\\target\\real.txt"),
+        );
+        let target_owners = index_targets(&tracked);
+
+        let event = Event::new(EventKind::Remove(notify::event::RemoveKind::Any))
+            .add_path(PathBuf::from("C:\\tmp\\target\\real.txt"));
+
+        let affected = affected_paths(&event, &tracked, &target_owners);
+        assert_eq!(affected, vec!["C:\\tmp\\a.lnk".to_string()]);
+    }
+
+    #[test]
+    fn only_create_modify_remove_and_any_kinds_are_relevant() {
+        assert!(is_relevant_kind(&EventKind::Create(notify::event::CreateKind::Any)));
+        assert!(is_relevant_kind(&EventKind::Modify(notify::event::ModifyKind::Any)));
+        assert!(is_relevant_kind(&EventKind::Remove(notify::event::RemoveKind::Any)));
+        assert!(is_relevant_kind(&EventKind::Any));
+        assert!(!is_relevant_kind(&EventKind::Access(notify::event::AccessKind::Any)));
+    }
+}
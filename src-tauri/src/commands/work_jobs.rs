@@ -0,0 +1,563 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use tokio::task::JoinSet;
+use uuid::Uuid;
+
+use crate::commands::export::write_atomic;
+use crate::commands::jobs::{job_controls, job_state_from_text, job_state_to_text, JobControl};
+use crate::commands::validate::validate_one;
+use crate::types::{ExportFormat, JobState, LinkEntry};
+
+const EXPORT_JOB_CONCURRENCY: usize = 8;
+const VALIDATE_JOB_CONCURRENCY: usize = 16;
+const WORK_JOB_FLUSH_ENTRIES: u64 = 50;
+const WORK_JOB_FLUSH_MILLIS: u128 = 1_000;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WorkKind {
+    Export,
+    Validate,
+}
+
+fn kind_to_text(kind: WorkKind) -> &'static str {
+    match kind {
+        WorkKind::Export => "Export",
+        WorkKind::Validate => "Validate",
+    }
+}
+
+fn kind_from_text(value: &str) -> WorkKind {
+    match value {
+        "Validate" => WorkKind::Validate,
+        _ => WorkKind::Export,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum WorkParams {
+    Export { path: String, format: ExportFormat },
+    Validate,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkJobReport {
+    pub id: String,
+    pub kind: WorkKind,
+    pub state: JobState,
+    pub processed: u64,
+    pub total: u64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+struct WorkJobRow {
+    report: WorkJobReport,
+    pending: Vec<LinkEntry>,
+    completed: Vec<LinkEntry>,
+    params: WorkParams,
+}
+
+fn encode_entries(entries: &[LinkEntry]) -> Result<Vec<u8>, String> {
+    rmp_serde::to_vec(entries).map_err(|e| format!("Failed to encode job entries: {e}"))
+}
+
+fn decode_entries(bytes: &[u8]) -> Result<Vec<LinkEntry>, String> {
+    rmp_serde::from_slice(bytes).map_err(|e| format!("Failed to decode job entries: {e}"))
+}
+
+fn encode_params(params: &WorkParams) -> Result<Vec<u8>, String> {
+    rmp_serde::to_vec(params).map_err(|e| format!("Failed to encode job params: {e}"))
+}
+
+fn decode_params(bytes: &[u8]) -> Result<WorkParams, String> {
+    rmp_serde::from_slice(bytes).map_err(|e| format!("Failed to decode job params: {e}"))
+}
+
+fn row_to_work_job(row: &rusqlite::Row) -> rusqlite::Result<Result<WorkJobRow, String>> {
+    let id: String = row.get(0)?;
+    let kind_text: String = row.get(1)?;
+    let state_text: String = row.get(2)?;
+    let processed: i64 = row.get(3)?;
+    let total: i64 = row.get(4)?;
+    let pending_bytes: Vec<u8> = row.get(5)?;
+    let completed_bytes: Vec<u8> = row.get(6)?;
+    let params_bytes: Vec<u8> = row.get(7)?;
+    let created_at: String = row.get(8)?;
+    let updated_at: String = row.get(9)?;
+
+    let decoded = (|| -> Result<WorkJobRow, String> {
+        Ok(WorkJobRow {
+            report: WorkJobReport {
+                id,
+                kind: kind_from_text(&kind_text),
+                state: job_state_from_text(&state_text),
+                processed: processed as u64,
+                total: total as u64,
+                created_at,
+                updated_at,
+            },
+            pending: decode_entries(&pending_bytes)?,
+            completed: decode_entries(&completed_bytes)?,
+            params: decode_params(&params_bytes)?,
+        })
+    })();
+
+    Ok(decoded)
+}
+
+fn upsert_work_job(conn: &Connection, row: &WorkJobRow) -> Result<(), String> {
+    let pending_bytes = encode_entries(&row.pending)?;
+    let completed_bytes = encode_entries(&row.completed)?;
+    let params_bytes = encode_params(&row.params)?;
+
+    conn.execute(
+        "
+        INSERT INTO work_jobs (id, kind, state, processed, total, pending, completed, params, created_at, updated_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?9)
+        ON CONFLICT(id) DO UPDATE SET
+            state = excluded.state,
+            processed = excluded.processed,
+            total = excluded.total,
+            pending = excluded.pending,
+            completed = excluded.completed,
+            updated_at = excluded.updated_at
+        ",
+        params![
+            row.report.id,
+            kind_to_text(row.report.kind),
+            job_state_to_text(row.report.state),
+            row.report.processed as i64,
+            row.report.total as i64,
+            pending_bytes,
+            completed_bytes,
+            params_bytes,
+            row.report.created_at,
+        ],
+    )
+    .map_err(|e| format!("Failed to persist work job {}: {e}", row.report.id))?;
+
+    Ok(())
+}
+
+fn load_work_job(conn: &Connection, job_id: &str) -> Result<Option<WorkJobRow>, String> {
+    let row = conn
+        .query_row(
+            "
+            SELECT id, kind, state, processed, total, pending, completed, params, created_at, updated_at
+            FROM work_jobs WHERE id = ?1
+            ",
+            params![job_id],
+            row_to_work_job,
+        )
+        .optional()
+        .map_err(|e| format!("Failed to load work job {job_id}: {e}"))?;
+
+    row.transpose()
+}
+
+/// Any job still marked `Running` when the app starts crashed or was killed
+/// mid-run; its pending queue survived in the DB, so it's re-offered as
+/// `Paused` for the frontend to resume rather than left stuck as "running".
+pub fn resume_interrupted_jobs_on_startup() -> Result<(), String> {
+    let conn = crate::db::open_connection()?;
+
+    let mut stmt = conn
+        .prepare("SELECT id FROM work_jobs WHERE state = ?1")
+        .map_err(|e| format!("Failed to prepare interrupted-job query: {e}"))?;
+
+    let ids = stmt
+        .query_map(params![job_state_to_text(JobState::Running)], |row| {
+            row.get::<_, String>(0)
+        })
+        .map_err(|e| format!("Failed to query interrupted jobs: {e}"))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to decode interrupted jobs: {e}"))?;
+
+    for id in ids {
+        conn.execute(
+            "UPDATE work_jobs SET state = ?1, updated_at = ?2 WHERE id = ?3",
+            params![job_state_to_text(JobState::Paused), Utc::now().to_rfc3339(), id],
+        )
+        .map_err(|e| format!("Failed to mark interrupted job {id} as paused: {e}"))?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_jobs() -> Result<Vec<WorkJobReport>, String> {
+    let conn = crate::db::open_connection()?;
+
+    let mut stmt = conn
+        .prepare(
+            "
+            SELECT id, kind, state, processed, total, pending, completed, params, created_at, updated_at
+            FROM work_jobs
+            ORDER BY updated_at DESC
+            ",
+        )
+        .map_err(|e| format!("Failed to prepare work job query: {e}"))?;
+
+    let rows = stmt
+        .query_map([], row_to_work_job)
+        .map_err(|e| format!("Failed to query work jobs: {e}"))?;
+
+    let mut reports = Vec::new();
+    for row in rows {
+        let decoded = row.map_err(|e| format!("Failed to decode work job row: {e}"))??;
+        reports.push(decoded.report);
+    }
+
+    Ok(reports)
+}
+
+async fn run_export_job(job_id: String, app: AppHandle, control: Arc<JobControl>) {
+    let Ok(conn) = crate::db::open_connection() else { return };
+    let Ok(Some(mut job)) = load_work_job(&conn, &job_id) else { return };
+
+    let WorkParams::Export { path, format } = job.params.clone() else {
+        return;
+    };
+
+    let mut pending = std::mem::take(&mut job.pending);
+    let mut set = JoinSet::new();
+    let mut last_flush = std::time::Instant::now();
+
+    loop {
+        if control.cancel.load(Ordering::SeqCst) {
+            job.report.state = JobState::Canceled;
+            job.report.updated_at = Utc::now().to_rfc3339();
+            job.pending = pending;
+            let _ = upsert_work_job(&conn, &job);
+            return;
+        }
+
+        if control.pause.load(Ordering::SeqCst) && set.is_empty() {
+            job.report.state = JobState::Paused;
+            job.report.updated_at = Utc::now().to_rfc3339();
+            job.pending = pending;
+            let _ = upsert_work_job(&conn, &job);
+            return;
+        }
+
+        while set.len() < EXPORT_JOB_CONCURRENCY && !control.pause.load(Ordering::SeqCst) {
+            match pending.pop() {
+                Some(entry) => {
+                    set.spawn(async move { entry });
+                }
+                None => break,
+            }
+        }
+
+        if set.is_empty() {
+            break;
+        }
+
+        if let Some(result) = set.join_next().await {
+            if let Ok(entry) = result {
+                job.completed.push(entry);
+                job.report.processed += 1;
+
+                let _ = app.emit(
+                    "work:progress",
+                    serde_json::json!({
+                        "jobId": job_id,
+                        "processed": job.report.processed,
+                        "total": job.report.total,
+                    }),
+                );
+
+                if job.report.processed % WORK_JOB_FLUSH_ENTRIES == 0
+                    || last_flush.elapsed().as_millis() >= WORK_JOB_FLUSH_MILLIS
+                {
+                    job.pending = pending.clone();
+                    let _ = upsert_work_job(&conn, &job);
+                    last_flush = std::time::Instant::now();
+                }
+            }
+        }
+    }
+
+    let write_result = write_atomic_export(&job.completed, &path, format);
+    job.report.state = if write_result.is_ok() {
+        JobState::Completed
+    } else {
+        JobState::Failed
+    };
+    job.report.updated_at = Utc::now().to_rfc3339();
+    job.pending = Vec::new();
+    let _ = upsert_work_job(&conn, &job);
+}
+
+fn write_atomic_export(entries: &[LinkEntry], path: &str, format: ExportFormat) -> Result<(), String> {
+    let details: Vec<_> = entries
+        .iter()
+        .filter_map(|entry| crate::commands::details::get_link_details(entry.path.clone()).ok())
+        .collect();
+
+    let content = match format {
+        ExportFormat::Json => serde_json::to_vec_pretty(&details)
+            .map_err(|e| format!("Failed to serialize job export as JSON: {e}"))?,
+        ExportFormat::Ndjson => {
+            let mut buffer = Vec::new();
+            for item in &details {
+                serde_json::to_writer(&mut buffer, item)
+                    .map_err(|e| format!("Failed to serialize job export record as NDJSON: {e}"))?;
+                buffer.push(b'\n');
+            }
+            buffer
+        }
+        ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer
+                .write_record(["link_path", "target_stored", "target_real", "link_type", "status"])
+                .map_err(|e| format!("Failed to write job export CSV header: {e}"))?;
+
+            for item in &details {
+                writer
+                    .write_record([
+                        item.path.clone(),
+                        item.target_stored.clone(),
+                        item.target_real.clone(),
+                        format!("{:?}", item.link_type),
+                        format!("{:?}", item.status),
+                    ])
+                    .map_err(|e| format!("Failed to write job export CSV row: {e}"))?;
+            }
+
+            writer
+                .into_inner()
+                .map_err(|e| format!("Failed to flush job export CSV writer: {e}"))?
+        }
+    };
+
+    write_atomic(std::path::Path::new(path), &content)
+}
+
+async fn run_validate_job(job_id: String, app: AppHandle, control: Arc<JobControl>) {
+    let Ok(conn) = crate::db::open_connection() else { return };
+    let Ok(Some(mut job)) = load_work_job(&conn, &job_id) else { return };
+
+    let mut pending = std::mem::take(&mut job.pending);
+    let mut set = JoinSet::new();
+    let mut last_flush = std::time::Instant::now();
+
+    loop {
+        if control.cancel.load(Ordering::SeqCst) {
+            job.report.state = JobState::Canceled;
+            job.report.updated_at = Utc::now().to_rfc3339();
+            job.pending = pending;
+            let _ = upsert_work_job(&conn, &job);
+            return;
+        }
+
+        if control.pause.load(Ordering::SeqCst) && set.is_empty() {
+            job.report.state = JobState::Paused;
+            job.report.updated_at = Utc::now().to_rfc3339();
+            job.pending = pending;
+            let _ = upsert_work_job(&conn, &job);
+            return;
+        }
+
+        while set.len() < VALIDATE_JOB_CONCURRENCY && !control.pause.load(Ordering::SeqCst) {
+            match pending.pop() {
+                Some(entry) => {
+                    set.spawn(validate_one(entry));
+                }
+                None => break,
+            }
+        }
+
+        if set.is_empty() {
+            break;
+        }
+
+        if let Some(result) = set.join_next().await {
+            if let Ok(entry) = result {
+                job.completed.push(entry);
+                job.report.processed += 1;
+
+                let _ = app.emit(
+                    "work:progress",
+                    serde_json::json!({
+                        "jobId": job_id,
+                        "processed": job.report.processed,
+                        "total": job.report.total,
+                    }),
+                );
+
+                if job.report.processed % WORK_JOB_FLUSH_ENTRIES == 0
+                    || last_flush.elapsed().as_millis() >= WORK_JOB_FLUSH_MILLIS
+                {
+                    job.pending = pending.clone();
+                    let _ = upsert_work_job(&conn, &job);
+                    last_flush = std::time::Instant::now();
+                }
+            }
+        }
+    }
+
+    job.report.state = JobState::Completed;
+    job.report.updated_at = Utc::now().to_rfc3339();
+    job.pending = Vec::new();
+    let _ = upsert_work_job(&conn, &job);
+}
+
+fn spawn_work_job(job_id: String, kind: WorkKind, app: AppHandle) -> Result<(), String> {
+    let control = Arc::new(JobControl {
+        cancel: AtomicBool::new(false),
+        pause: AtomicBool::new(false),
+    });
+
+    job_controls()
+        .lock()
+        .map_err(|_| "Job registry lock poisoned".to_string())?
+        .insert(job_id.clone(), control.clone());
+
+    std::thread::spawn(move || {
+        let runtime = match tokio::runtime::Runtime::new() {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+
+        runtime.block_on(async move {
+            match kind {
+                WorkKind::Export => run_export_job(job_id, app, control).await,
+                WorkKind::Validate => run_validate_job(job_id, app, control).await,
+            }
+        });
+    });
+
+    Ok(())
+}
+
+fn start_job(kind: WorkKind, entries: Vec<LinkEntry>, params: WorkParams, app: AppHandle) -> Result<String, String> {
+    let job_id = Uuid::new_v4().to_string();
+    let now = Utc::now().to_rfc3339();
+    let total = entries.len() as u64;
+
+    let conn = crate::db::open_connection()?;
+    upsert_work_job(
+        &conn,
+        &WorkJobRow {
+            report: WorkJobReport {
+                id: job_id.clone(),
+                kind,
+                state: JobState::Running,
+                processed: 0,
+                total,
+                created_at: now.clone(),
+                updated_at: now,
+            },
+            pending: entries,
+            completed: Vec::new(),
+            params,
+        },
+    )?;
+
+    spawn_work_job(job_id.clone(), kind, app)?;
+    Ok(job_id)
+}
+
+#[tauri::command]
+pub fn start_export_job(
+    app: AppHandle,
+    entries: Vec<LinkEntry>,
+    path: String,
+    format: ExportFormat,
+) -> Result<String, String> {
+    start_job(WorkKind::Export, entries, WorkParams::Export { path, format }, app)
+}
+
+#[tauri::command]
+pub fn start_validate_job(app: AppHandle, entries: Vec<LinkEntry>) -> Result<String, String> {
+    start_job(WorkKind::Validate, entries, WorkParams::Validate, app)
+}
+
+#[tauri::command]
+pub fn pause_job(job_id: String) -> Result<(), String> {
+    let controls = job_controls()
+        .lock()
+        .map_err(|_| "Job registry lock poisoned".to_string())?;
+
+    let control = controls
+        .get(&job_id)
+        .ok_or_else(|| format!("No active job with id {job_id}"))?;
+
+    control.pause.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn resume_job(job_id: String, app: AppHandle) -> Result<(), String> {
+    let conn = crate::db::open_connection()?;
+    let job = load_work_job(&conn, &job_id)?.ok_or_else(|| format!("Unknown job {job_id}"))?;
+
+    if job.report.state != JobState::Paused && job.report.state != JobState::Failed {
+        return Err(format!("Job {job_id} is not paused or failed"));
+    }
+
+    conn.execute(
+        "UPDATE work_jobs SET state = ?1, updated_at = ?2 WHERE id = ?3",
+        params![job_state_to_text(JobState::Running), Utc::now().to_rfc3339(), job_id],
+    )
+    .map_err(|e| format!("Failed to mark job {job_id} running: {e}"))?;
+
+    spawn_work_job(job_id, job.report.kind, app)
+}
+
+#[tauri::command]
+pub fn cancel_job(job_id: String) -> Result<(), String> {
+    let controls = job_controls()
+        .lock()
+        .map_err(|_| "Job registry lock poisoned".to_string())?;
+
+    if let Some(control) = controls.get(&job_id) {
+        control.cancel.store(true, Ordering::SeqCst);
+        return Ok(());
+    }
+    drop(controls);
+
+    let conn = crate::db::open_connection()?;
+    conn.execute(
+        "UPDATE work_jobs SET state = ?1, updated_at = ?2 WHERE id = ?3",
+        params![job_state_to_text(JobState::Canceled), Utc::now().to_rfc3339(), job_id],
+    )
+    .map_err(|e| format!("Failed to mark job {job_id} canceled: {e}"))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn work_kind_round_trips_through_text() {
+        for kind in [WorkKind::Export, WorkKind::Validate] {
+            assert_eq!(kind_from_text(kind_to_text(kind)), kind);
+        }
+    }
+
+    #[test]
+    fn entries_round_trip_through_messagepack() {
+        let entries = vec![LinkEntry {
+            path: "C:\\tmp\\a".to_string(),
+            target: "C:\\tmp\\b".to_string(),
+            link_type: crate::types::LinkType::Symlink,
+            status: crate::types::LinkStatus::Ok,
+            hardlink_siblings: Vec::new(),
+        }];
+
+        let encoded = encode_entries(&entries).unwrap();
+        let decoded = decode_entries(&encoded).unwrap();
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].path, "C:\\tmp\\a");
+    }
+}
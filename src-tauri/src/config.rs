@@ -15,6 +15,8 @@ pub struct Config {
     pub scan: ScanConfig,
     pub ui: UiConfig,
     pub shell: ShellConfig,
+    pub db: DbConfig,
+    pub policy: PolicyConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +24,7 @@ pub struct Config {
 pub struct ScanConfig {
     pub default_volume: String,
     pub excluded_paths: Vec<String>,
+    pub included_paths: Vec<String>,
     pub auto_scan_on_start: bool,
 }
 
@@ -39,21 +42,50 @@ pub struct ShellConfig {
     pub context_menu_registered: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DbConfig {
+    pub busy_timeout_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PolicyConfig {
+    pub script_path: Option<String>,
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             scan: ScanConfig::default(),
             ui: UiConfig::default(),
             shell: ShellConfig::default(),
+            db: DbConfig::default(),
+            policy: PolicyConfig::default(),
+        }
+    }
+}
+
+impl Default for DbConfig {
+    fn default() -> Self {
+        Self {
+            busy_timeout_ms: 5_000,
         }
     }
 }
 
+impl Default for PolicyConfig {
+    fn default() -> Self {
+        Self { script_path: None }
+    }
+}
+
 impl Default for ScanConfig {
     fn default() -> Self {
         Self {
             default_volume: default_system_drive(),
             excluded_paths: vec!["C:\\Windows\\WinSxS".to_string()],
+            included_paths: Vec::new(),
             auto_scan_on_start: false,
         }
     }
@@ -89,7 +121,7 @@ fn default_config() -> Config {
     Config::default()
 }
 
-fn symview_dir() -> Result<PathBuf, String> {
+pub(crate) fn symview_dir() -> Result<PathBuf, String> {
     let home = dirs::home_dir().ok_or_else(|| "Cannot resolve home directory".to_string())?;
     let dir = home.join("symview");
 
@@ -251,10 +283,13 @@ mod tests {
 
         assert_eq!(parsed.scan.default_volume, "D:");
         assert_eq!(parsed.scan.excluded_paths, vec!["C:\\Windows\\WinSxS".to_string()]);
+        assert!(parsed.scan.included_paths.is_empty());
         assert!(!parsed.scan.auto_scan_on_start);
         assert!(parsed.ui.remember_filters);
         assert_eq!(parsed.ui.last_filter_type, "All");
         assert_eq!(parsed.ui.last_filter_status, "All");
         assert!(!parsed.shell.context_menu_registered);
+        assert_eq!(parsed.db.busy_timeout_ms, 5_000);
+        assert_eq!(parsed.policy.script_path, None);
     }
 }
@@ -1,9 +1,16 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
 use chrono::Utc;
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Deserialize;
 use tauri::AppHandle;
+use uuid::Uuid;
 
+use super::history_query;
 use crate::commands::links::{create_link_internal, delete_link_internal, retarget_link_internal};
-use crate::types::{ActionRecord, LinkType};
+use crate::types::{ActionRecord, ExportFormat, LinkType};
 
 const MAX_HISTORY_LIMIT: u32 = 1_000;
 
@@ -34,16 +41,81 @@ fn link_type_from_text(value: &str) -> LinkType {
     }
 }
 
+/// Allocates a `group_id` for a new batch, so every `log_action` call a
+/// bulk operation like `create_links` makes can be tagged with it and
+/// undo/redo the whole batch as one unit instead of one row at a time.
+/// The id is handed back to the caller to thread through
+/// `log_grouped_action` explicitly — earlier this rode on a process-global
+/// "current group" instead, which let two bulk commands running
+/// concurrently (Tauri commands are just async tasks) stomp on each
+/// other's group tagging.
+pub(crate) fn begin_batch() -> String {
+    Uuid::new_v4().to_string()
+}
+
 pub fn log_action(conn: &Connection, action: ActionInput) -> Result<i64, String> {
+    insert_action_row(conn, &action, None, None, None)
+}
+
+/// Like `log_action`, but tags the row with `group_id` instead of leaving
+/// it ungrouped. Used by bulk commands for every row logged within one
+/// `begin_batch()`-allocated batch.
+pub(crate) fn log_grouped_action(conn: &Connection, action: ActionInput, group_id: &str) -> Result<i64, String> {
+    insert_action_row(conn, &action, None, None, Some(group_id))
+}
+
+/// Inserts an action row, optionally recording which earlier action it
+/// reverses. `Undo` rows set this to the action they reversed; `Redo` rows
+/// set it to the `Undo` row they consumed. Every other action type leaves
+/// both columns `NULL`. Undo/redo of a single (non-batched) action is
+/// never grouped.
+fn log_action_with_undo_link(
+    conn: &Connection,
+    action: ActionInput,
+    undone_action_type: Option<&str>,
+    undone_action_id: Option<i64>,
+) -> Result<i64, String> {
+    insert_action_row(conn, &action, undone_action_type, undone_action_id, None)
+}
+
+fn insert_action_row(
+    conn: &Connection,
+    action: &ActionInput,
+    undone_action_type: Option<&str>,
+    undone_action_id: Option<i64>,
+    group_id: Option<&str>,
+) -> Result<i64, String> {
     let tx = conn
         .unchecked_transaction()
         .map_err(|e| format!("Failed to start transaction: {e}"))?;
 
-    tx.execute(
+    let timestamp = Utc::now().to_rfc3339();
+    let id = insert_action_values(&tx, action, &timestamp, undone_action_type, undone_action_id, group_id)?;
+
+    tx.commit()
+        .map_err(|e| format!("Failed to commit transaction: {e}"))?;
+
+    Ok(id)
+}
+
+/// The raw `actions` insert shared by every write path in this module. Takes
+/// `conn` rather than opening its own transaction, so callers that need to
+/// insert many rows atomically (like `import_history`) can wrap a single
+/// transaction around several calls instead of nesting one per row.
+fn insert_action_values(
+    conn: &Connection,
+    action: &ActionInput,
+    timestamp: &str,
+    undone_action_type: Option<&str>,
+    undone_action_id: Option<i64>,
+    group_id: Option<&str>,
+) -> Result<i64, String> {
+    conn.execute(
         "
         INSERT INTO actions (
-          action_type, link_path, link_type, target_old, target_new, timestamp, success, error_msg
-        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+          action_type, link_path, link_type, target_old, target_new, timestamp, success, error_msg,
+          undone_action_type, undone_action_id, group_id
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
         ",
         params![
             action.action_type,
@@ -51,19 +123,31 @@ pub fn log_action(conn: &Connection, action: ActionInput) -> Result<i64, String>
             link_type_to_text(&action.link_type),
             action.target_old,
             action.target_new,
-            Utc::now().to_rfc3339(),
+            timestamp,
             if action.success { 1 } else { 0 },
-            action.error_msg
+            action.error_msg,
+            undone_action_type,
+            undone_action_id,
+            group_id,
         ],
     )
     .map_err(|e| format!("Failed to insert action: {e}"))?;
 
-    let id = tx.last_insert_rowid();
-
-    tx.commit()
-        .map_err(|e| format!("Failed to commit transaction: {e}"))?;
+    Ok(conn.last_insert_rowid())
+}
 
-    Ok(id)
+fn row_to_action_record(row: &rusqlite::Row) -> rusqlite::Result<ActionRecord> {
+    Ok(ActionRecord {
+        id: row.get::<_, i64>(0)?,
+        action_type: row.get::<_, String>(1)?,
+        link_path: row.get::<_, String>(2)?,
+        link_type: link_type_from_text(&row.get::<_, String>(3)?),
+        target_old: row.get::<_, Option<String>>(4)?,
+        target_new: row.get::<_, Option<String>>(5)?,
+        timestamp: row.get::<_, String>(6)?,
+        success: row.get::<_, i64>(7)? == 1,
+        error_msg: row.get::<_, Option<String>>(8)?,
+    })
 }
 
 #[tauri::command]
@@ -84,122 +168,647 @@ pub fn get_history(limit: u32, offset: u32) -> Result<Vec<ActionRecord>, String>
         .map_err(|e| format!("Failed to prepare history query: {e}"))?;
 
     let rows = stmt
-        .query_map(params![safe_limit, safe_offset], |row| {
-            Ok(ActionRecord {
-                id: row.get::<_, i64>(0)?,
-                action_type: row.get::<_, String>(1)?,
-                link_path: row.get::<_, String>(2)?,
-                link_type: link_type_from_text(&row.get::<_, String>(3)?),
-                target_old: row.get::<_, Option<String>>(4)?,
-                target_new: row.get::<_, Option<String>>(5)?,
-                timestamp: row.get::<_, String>(6)?,
-                success: row.get::<_, i64>(7)? == 1,
-                error_msg: row.get::<_, Option<String>>(8)?,
-            })
-        })
+        .query_map(params![safe_limit, safe_offset], row_to_action_record)
         .map_err(|e| format!("Failed to query history: {e}"))?;
 
     rows.collect::<Result<Vec<_>, _>>()
         .map_err(|e| format!("Failed to decode history rows: {e}"))
 }
 
-type UndoCandidate = (String, String, String, Option<String>, Option<String>);
+/// Filters the action log with the small query DSL in `history_query`, e.g.
+/// `type = "Delete" AND path ~ "node_modules"`. See that module for the
+/// supported fields, operators, and grammar.
+#[tauri::command]
+pub fn search_history(query: String, limit: u32, offset: u32) -> Result<Vec<ActionRecord>, String> {
+    let (where_clause, mut params) = history_query::compile(&query)?;
+
+    let conn = crate::db::open_connection()?;
+    let safe_limit = limit.min(MAX_HISTORY_LIMIT) as i64;
+    let safe_offset = offset as i64;
+
+    params.push(rusqlite::types::Value::Integer(safe_limit));
+    params.push(rusqlite::types::Value::Integer(safe_offset));
+
+    let sql = format!(
+        "
+        SELECT id, action_type, link_path, link_type, target_old, target_new, timestamp, success, error_msg
+        FROM actions
+        WHERE {where_clause}
+        ORDER BY id DESC
+        LIMIT ? OFFSET ?
+        "
+    );
+
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|e| format!("Failed to prepare history search query: {e}"))?;
+
+    let rows = stmt
+        .query_map(rusqlite::params_from_iter(params.iter()), row_to_action_record)
+        .map_err(|e| format!("Failed to query history search: {e}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to decode history search rows: {e}"))
+}
 
-fn latest_undo_candidate(conn: &Connection) -> Result<Option<UndoCandidate>, String> {
+/// Streams every row in `actions` (newest-first, no `MAX_HISTORY_LIMIT` cap)
+/// to `on_record` one at a time, so a full-log export never has to buffer
+/// the whole history in memory.
+fn stream_all_actions(
+    conn: &Connection,
+    mut on_record: impl FnMut(ActionRecord) -> Result<(), String>,
+) -> Result<(), String> {
     let mut stmt = conn
         .prepare(
             "
-            SELECT action_type, link_path, link_type, target_old, target_new
+            SELECT id, action_type, link_path, link_type, target_old, target_new, timestamp, success, error_msg
             FROM actions
-            WHERE success = 1
             ORDER BY id DESC
             ",
         )
-        .map_err(|e| format!("Failed to prepare undo query: {e}"))?;
+        .map_err(|e| format!("Failed to prepare history export query: {e}"))?;
 
     let mut rows = stmt
         .query([])
-        .map_err(|e| format!("Failed to execute undo query: {e}"))?;
-
-    let mut pending_undo_count = 0_u32;
+        .map_err(|e| format!("Failed to query history for export: {e}"))?;
 
     while let Some(row) = rows
         .next()
-        .map_err(|e| format!("Failed to decode undo query row: {e}"))?
+        .map_err(|e| format!("Failed to read history export row: {e}"))?
     {
-        let action_type = row
-            .get::<_, String>(0)
-            .map_err(|e| format!("Failed to read undo action type: {e}"))?;
+        let record = row_to_action_record(row).map_err(|e| format!("Failed to decode history export row: {e}"))?;
+        on_record(record)?;
+    }
 
-        if action_type == "Undo" {
-            pending_undo_count = pending_undo_count.saturating_add(1);
-            continue;
+    Ok(())
+}
+
+const ACTION_CSV_COLUMNS: [&str; 9] = [
+    "id",
+    "action_type",
+    "link_path",
+    "link_type",
+    "target_old",
+    "target_new",
+    "timestamp",
+    "success",
+    "error_msg",
+];
+
+fn action_record_to_csv_row(record: &ActionRecord) -> [String; 9] {
+    [
+        record.id.to_string(),
+        record.action_type.clone(),
+        record.link_path.clone(),
+        link_type_to_text(&record.link_type).to_string(),
+        record.target_old.clone().unwrap_or_default(),
+        record.target_new.clone().unwrap_or_default(),
+        record.timestamp.clone(),
+        record.success.to_string(),
+        record.error_msg.clone().unwrap_or_default(),
+    ]
+}
+
+/// Exports the full action log (unlike `get_history`/`search_history`, there
+/// is no `MAX_HISTORY_LIMIT` cap) to `path` as JSON, NDJSON, or CSV. Rows
+/// are streamed straight to a temp file as they come out of the query
+/// rather than collected into a `Vec` first, so a very large history
+/// doesn't have to be buffered fully in memory; the temp file is then
+/// renamed over `path`, the same crash-safety trick `write_atomic` uses for
+/// the other export commands. Returns the record count.
+#[tauri::command]
+pub fn export_history(path: String, format: ExportFormat) -> Result<usize, String> {
+    let conn = crate::db::open_connection()?;
+    let mut count = 0_usize;
+    let destination = std::path::PathBuf::from(&path);
+    let dir = destination
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let file_name = destination
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("symview-history-export");
+    let temp_path = dir.join(format!(".{file_name}.tmp"));
+
+    let result = export_history_to(&temp_path, format, &conn, &mut count);
+
+    match result {
+        Ok(()) => {
+            if let Err(error) = std::fs::rename(&temp_path, &destination) {
+                let _ = std::fs::remove_file(&temp_path);
+                return Err(format!("Failed to finalize history export file: {error}"));
+            }
+            Ok(count)
         }
+        Err(error) => {
+            let _ = std::fs::remove_file(&temp_path);
+            Err(error)
+        }
+    }
+}
 
-        if pending_undo_count > 0 {
-            pending_undo_count -= 1;
+fn export_history_to(
+    temp_path: &Path,
+    format: ExportFormat,
+    conn: &Connection,
+    count: &mut usize,
+) -> Result<(), String> {
+    match format {
+        ExportFormat::Json => {
+            let file =
+                File::create(temp_path).map_err(|e| format!("Failed to create history export file: {e}"))?;
+            let mut writer = BufWriter::new(file);
+
+            writer
+                .write_all(b"[")
+                .map_err(|e| format!("Failed to start history JSON array: {e}"))?;
+
+            stream_all_actions(conn, |record| {
+                if *count > 0 {
+                    writer
+                        .write_all(b",")
+                        .map_err(|e| format!("Failed to write history JSON separator: {e}"))?;
+                }
+                serde_json::to_writer(&mut writer, &record)
+                    .map_err(|e| format!("Failed to serialize history record as JSON: {e}"))?;
+                *count += 1;
+                Ok(())
+            })?;
+
+            writer
+                .write_all(b"]")
+                .map_err(|e| format!("Failed to close history JSON array: {e}"))?;
+            writer
+                .flush()
+                .map_err(|e| format!("Failed to flush history JSON writer: {e}"))?;
+        }
+        ExportFormat::Ndjson => {
+            let file =
+                File::create(temp_path).map_err(|e| format!("Failed to create history export file: {e}"))?;
+            let mut writer = BufWriter::new(file);
+
+            stream_all_actions(conn, |record| {
+                serde_json::to_writer(&mut writer, &record)
+                    .map_err(|e| format!("Failed to serialize history record as NDJSON: {e}"))?;
+                writer
+                    .write_all(b"\n")
+                    .map_err(|e| format!("Failed to write history NDJSON newline: {e}"))?;
+                *count += 1;
+                Ok(())
+            })?;
+
+            writer
+                .flush()
+                .map_err(|e| format!("Failed to flush history NDJSON writer: {e}"))?;
+        }
+        ExportFormat::Csv => {
+            let file =
+                File::create(temp_path).map_err(|e| format!("Failed to create history export file: {e}"))?;
+            let mut writer = csv::Writer::from_writer(BufWriter::new(file));
+
+            writer
+                .write_record(ACTION_CSV_COLUMNS)
+                .map_err(|e| format!("Failed to write history CSV header: {e}"))?;
+
+            stream_all_actions(conn, |record| {
+                writer
+                    .write_record(action_record_to_csv_row(&record))
+                    .map_err(|e| format!("Failed to write history CSV row: {e}"))?;
+                *count += 1;
+                Ok(())
+            })?;
+
+            writer
+                .flush()
+                .map_err(|e| format!("Failed to flush history CSV writer: {e}"))?;
+        }
+    }
+
+    Ok(())
+}
+
+const KNOWN_ACTION_TYPES: [&str; 7] = [
+    "Create",
+    "Delete",
+    "Retarget",
+    "SetPermissions",
+    "Restore",
+    "Undo",
+    "Redo",
+];
+
+fn validate_action_type(action_type: &str) -> Result<(), String> {
+    if KNOWN_ACTION_TYPES.contains(&action_type) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Unknown action_type '{action_type}' in history import (expected one of: {})",
+            KNOWN_ACTION_TYPES.join(", ")
+        ))
+    }
+}
+
+fn parse_link_type_strict(value: &str) -> Result<LinkType, String> {
+    match value {
+        "Symlink" => Ok(LinkType::Symlink),
+        "Junction" => Ok(LinkType::Junction),
+        "Hardlink" => Ok(LinkType::Hardlink),
+        other => Err(format!(
+            "Unknown link_type '{other}' in history import (expected one of: Symlink, Junction, Hardlink)"
+        )),
+    }
+}
+
+/// The shape of one imported row, before `link_type`/`action_type` have
+/// been validated. `id` is intentionally absent: imported rows get a fresh
+/// autoincrement id, since de-duplication goes by `(timestamp, action_type,
+/// link_path)` instead.
+#[derive(Debug, Clone, Deserialize)]
+struct ImportRow {
+    action_type: String,
+    link_path: String,
+    link_type: String,
+    target_old: Option<String>,
+    target_new: Option<String>,
+    timestamp: String,
+    success: bool,
+    error_msg: Option<String>,
+}
+
+fn parse_import_json(path: &str) -> Result<Vec<ImportRow>, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open history import file: {e}"))?;
+    serde_json::from_reader(BufReader::new(file)).map_err(|e| format!("Failed to parse history import JSON: {e}"))
+}
+
+fn parse_import_csv(path: &str) -> Result<Vec<ImportRow>, String> {
+    let mut reader =
+        csv::Reader::from_path(path).map_err(|e| format!("Failed to open history import file: {e}"))?;
+
+    reader
+        .deserialize::<ImportRow>()
+        .map(|row| row.map_err(|e| format!("Failed to parse history import CSV row: {e}")))
+        .collect()
+}
+
+/// Validates and inserts `rows` in a single transaction, reusing
+/// `insert_action_values` (the same insert `log_action` goes through) for
+/// each row. Skips any row whose `(timestamp, action_type, link_path)`
+/// already exists, so importing the same file twice is a no-op the second
+/// time. Returns the number of rows actually inserted.
+fn insert_imported_actions(conn: &Connection, rows: Vec<ImportRow>) -> Result<usize, String> {
+    for row in &rows {
+        validate_action_type(&row.action_type)?;
+        parse_link_type_strict(&row.link_type)?;
+    }
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Failed to start import transaction: {e}"))?;
+
+    let mut imported = 0_usize;
+
+    for row in rows {
+        let already_exists = tx
+            .query_row(
+                "SELECT 1 FROM actions WHERE timestamp = ?1 AND action_type = ?2 AND link_path = ?3 LIMIT 1",
+                params![row.timestamp, row.action_type, row.link_path],
+                |_| Ok(()),
+            )
+            .optional()
+            .map_err(|e| format!("Failed to check for an existing action during import: {e}"))?
+            .is_some();
+
+        if already_exists {
             continue;
         }
 
-        return Ok(Some((
-            action_type,
-            row.get::<_, String>(1)
-                .map_err(|e| format!("Failed to read undo link path: {e}"))?,
-            row.get::<_, String>(2)
-                .map_err(|e| format!("Failed to read undo link type: {e}"))?,
-            row.get::<_, Option<String>>(3)
-                .map_err(|e| format!("Failed to read undo old target: {e}"))?,
-            row.get::<_, Option<String>>(4)
-                .map_err(|e| format!("Failed to read undo new target: {e}"))?,
-        )));
+        let link_type = parse_link_type_strict(&row.link_type)?;
+
+        insert_action_values(
+            &tx,
+            &ActionInput {
+                action_type: row.action_type.clone(),
+                link_path: row.link_path.clone(),
+                link_type,
+                target_old: row.target_old.clone(),
+                target_new: row.target_new.clone(),
+                success: row.success,
+                error_msg: row.error_msg.clone(),
+            },
+            &row.timestamp,
+            None,
+            None,
+            None,
+        )?;
+
+        imported += 1;
     }
 
-    Ok(None)
+    tx.commit()
+        .map_err(|e| format!("Failed to commit import transaction: {e}"))?;
+
+    Ok(imported)
 }
 
+/// Imports an action log previously written by `export_history`, inferring
+/// JSON vs CSV from the file extension. Returns the number of rows actually
+/// inserted (rows already present, matched by `(timestamp, action_type,
+/// link_path)`, are skipped).
 #[tauri::command]
-pub fn undo_last(_app: AppHandle) -> Result<(), String> {
+pub fn import_history(path: String) -> Result<usize, String> {
+    let extension = std::path::Path::new(&path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+
+    let rows = match extension.as_deref() {
+        Some("csv") => parse_import_csv(&path)?,
+        Some("json") => parse_import_json(&path)?,
+        other => {
+            return Err(format!(
+                "Unsupported history import file extension: {}",
+                other.unwrap_or("<none>")
+            ))
+        }
+    };
+
     let conn = crate::db::open_connection()?;
+    insert_imported_actions(&conn, rows)
+}
 
-    let row = latest_undo_candidate(&conn)?.ok_or_else(|| "Nothing to undo".to_string())?;
+/// A row from `actions` as seen by the undo/redo machinery: enough fields
+/// to both replay the action (forward or reverse) and to tell whether it's
+/// part of a batch.
+#[derive(Debug, Clone)]
+struct ActionRow {
+    id: i64,
+    action_type: String,
+    link_path: String,
+    link_type: String,
+    target_old: Option<String>,
+    target_new: Option<String>,
+    group_id: Option<String>,
+    undone_action_type: Option<String>,
+}
+
+fn row_to_action_row(row: &rusqlite::Row) -> rusqlite::Result<ActionRow> {
+    Ok(ActionRow {
+        id: row.get(0)?,
+        action_type: row.get(1)?,
+        link_path: row.get(2)?,
+        link_type: row.get(3)?,
+        target_old: row.get(4)?,
+        target_new: row.get(5)?,
+        group_id: row.get(6)?,
+        undone_action_type: row.get(7)?,
+    })
+}
+
+const ACTION_ROW_COLUMNS: &str =
+    "id, action_type, link_path, link_type, target_old, target_new, group_id, undone_action_type";
 
-    let link_type = link_type_from_text(&row.2);
-    let result = match row.0.as_str() {
+/// Reverses a single action row: a `Create` is undone by deleting the link,
+/// a `Delete` by recreating it at its old target, a `Retarget` by putting
+/// the old target back.
+async fn apply_reverse(row: &ActionRow) -> Result<(), String> {
+    let link_type = link_type_from_text(&row.link_type);
+
+    match row.action_type.as_str() {
         "Delete" => {
             let target = row
-                .3
+                .target_old
                 .clone()
                 .ok_or_else(|| "Delete action is missing previous target".to_string())?;
 
-            create_link_internal(&row.1, &target, &link_type, false)
+            create_link_internal(&row.link_path, &target, &link_type, false).await
         }
-        "Create" => delete_link_internal(&row.1),
+        "Create" => delete_link_internal(&row.link_path).await,
         "Retarget" => {
             let old_target = row
-                .3
+                .target_old
                 .clone()
                 .ok_or_else(|| "Retarget action is missing old target".to_string())?;
-            retarget_link_internal(&row.1, &old_target)
+            retarget_link_internal(&row.link_path, &old_target).await
         }
         other => Err(format!("Undo is not supported for action: {other}")),
+    }
+}
+
+/// Re-applies a single action row in its original direction: the inverse
+/// of `apply_reverse`, used both by plain redo and by replaying a whole
+/// undone group forward.
+async fn apply_forward(row: &ActionRow) -> Result<(), String> {
+    let link_type = link_type_from_text(&row.link_type);
+
+    match row.action_type.as_str() {
+        "Create" => {
+            let target = row
+                .target_new
+                .clone()
+                .ok_or_else(|| "Create action is missing its target".to_string())?;
+
+            create_link_internal(&row.link_path, &target, &link_type, false).await
+        }
+        "Delete" => delete_link_internal(&row.link_path).await,
+        "Retarget" => {
+            let new_target = row
+                .target_new
+                .clone()
+                .ok_or_else(|| "Retarget action is missing its new target".to_string())?;
+            retarget_link_internal(&row.link_path, &new_target).await
+        }
+        other => Err(format!("Redo is not supported for action: {other}")),
+    }
+}
+
+/// Finds every successful real action (`Create`/`Delete`/`Retarget`)
+/// sharing `group_id`, ordered from newest to oldest.
+fn group_members(conn: &Connection, group_id: &str) -> Result<Vec<ActionRow>, String> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "
+            SELECT {ACTION_ROW_COLUMNS}
+            FROM actions
+            WHERE group_id = ?1 AND success = 1 AND action_type IN ('Create', 'Delete', 'Retarget')
+            ORDER BY id DESC
+            "
+        ))
+        .map_err(|e| format!("Failed to prepare group lookup query: {e}"))?;
+
+    let rows = stmt
+        .query_map(params![group_id], row_to_action_row)
+        .map_err(|e| format!("Failed to query group members: {e}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to decode group members: {e}"))
+}
+
+/// How many rows starting at `start` belong to the same batch as `rows[start]`
+/// (1 if it isn't grouped). Used to skip a whole already-undone group at once
+/// when it's credited against a single `Undo` row.
+fn skips_in_group(rows: &[ActionRow], start: usize) -> usize {
+    let Some(group_id) = rows[start].group_id.as_deref() else {
+        return 1;
     };
 
+    let mut end = start + 1;
+    while end < rows.len() && rows[end].group_id.as_deref() == Some(group_id) {
+        end += 1;
+    }
+
+    end - start
+}
+
+/// The action types `latest_undo_candidate` will ever hand back: the only
+/// ones `apply_reverse` knows how to invert. `SetPermissions` and `Restore`
+/// are real, successfully-logged actions too, but neither records the prior
+/// state needed to reverse it, so the undo walk must see straight through
+/// them to whatever reversible action came before.
+const REVERSIBLE_ACTION_TYPES: [&str; 3] = ["Create", "Delete", "Retarget"];
+
+/// Walks the action log newest-first looking for the next real action to
+/// undo. `Undo` rows are credits against the reversible rows below them: a
+/// row consumed by an earlier `Undo` is skipped, along with the rest of its
+/// batch if it belongs to one (since one `Undo` row can stand for a whole
+/// group). A `Redo` cancels the nearest `Undo` below it instead of leaving
+/// it as a credit, since redoing an action puts it back in play for undo
+/// the same as if it had never been undone. Irreversible actions
+/// (`SetPermissions`, `Restore`) never carry a credit and are skipped
+/// outright. The first un-consumed reversible row is the undo candidate.
+fn latest_undo_candidate(conn: &Connection) -> Result<Option<ActionRow>, String> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "
+            SELECT {ACTION_ROW_COLUMNS}
+            FROM actions
+            WHERE success = 1
+            ORDER BY id DESC
+            "
+        ))
+        .map_err(|e| format!("Failed to prepare undo query: {e}"))?;
+
+    let rows = stmt
+        .query_map([], row_to_action_row)
+        .map_err(|e| format!("Failed to execute undo query: {e}"))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to decode undo query rows: {e}"))?;
+
+    let mut index = 0;
+    let mut pending_undo_count = 0_u32;
+    let mut pending_redo_count = 0_u32;
+
+    while index < rows.len() {
+        let row = &rows[index];
+
+        if row.action_type == "Redo" {
+            pending_redo_count = pending_redo_count.saturating_add(1);
+            index += 1;
+            continue;
+        }
+
+        if row.action_type == "Undo" {
+            if pending_redo_count > 0 {
+                pending_redo_count -= 1;
+            } else {
+                pending_undo_count = pending_undo_count.saturating_add(1);
+            }
+            index += 1;
+            continue;
+        }
+
+        if !REVERSIBLE_ACTION_TYPES.contains(&row.action_type.as_str()) {
+            index += 1;
+            continue;
+        }
+
+        if pending_undo_count > 0 {
+            pending_undo_count -= 1;
+            index += skips_in_group(&rows, index);
+            continue;
+        }
+
+        return Ok(Some(row.clone()));
+    }
+
+    Ok(None)
+}
+
+/// Mirrors `latest_undo_candidate`, but walks `Redo` markers against `Undo`
+/// rows instead of `Undo` markers against real actions: a `Redo` consumes
+/// the next unconsumed `Undo`, and the first `Undo` left unconsumed is the
+/// redo candidate. Hitting any other action type before finding that `Undo`
+/// means a new action was logged after it, which clears the redo stack.
+/// Unlike undo, `Undo`/`Redo` rows always represent exactly one event
+/// (single-row or whole-group), so no group-span skipping is needed here.
+fn latest_redo_candidate(conn: &Connection) -> Result<Option<ActionRow>, String> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "
+            SELECT {ACTION_ROW_COLUMNS}
+            FROM actions
+            WHERE success = 1
+            ORDER BY id DESC
+            "
+        ))
+        .map_err(|e| format!("Failed to prepare redo query: {e}"))?;
+
+    let mut rows = stmt
+        .query([])
+        .map_err(|e| format!("Failed to execute redo query: {e}"))?;
+
+    let mut pending_redo_count = 0_u32;
+
+    while let Some(row) = rows
+        .next()
+        .map_err(|e| format!("Failed to decode redo query row: {e}"))?
+    {
+        let row = row_to_action_row(row).map_err(|e| format!("Failed to decode redo query row: {e}"))?;
+
+        if row.action_type == "Redo" {
+            pending_redo_count = pending_redo_count.saturating_add(1);
+            continue;
+        }
+
+        if row.action_type != "Undo" {
+            return Ok(None);
+        }
+
+        if pending_redo_count > 0 {
+            pending_redo_count -= 1;
+            continue;
+        }
+
+        if row.undone_action_type.is_none() {
+            return Err("Undo row is missing its recorded action type".to_string());
+        }
+
+        return Ok(Some(row));
+    }
+
+    Ok(None)
+}
+
+async fn undo_single(conn: &Connection, row: ActionRow) -> Result<(), String> {
+    let result = apply_reverse(&row).await;
     let (success, error_msg) = match result {
         Ok(_) => (true, None),
         Err(error) => (false, Some(error)),
     };
 
-    log_action(
-        &conn,
+    log_action_with_undo_link(
+        conn,
         ActionInput {
             action_type: "Undo".to_string(),
-            link_path: row.1,
-            link_type,
-            target_old: row.3,
-            target_new: row.4,
+            link_path: row.link_path.clone(),
+            link_type: link_type_from_text(&row.link_type),
+            target_old: row.target_old.clone(),
+            target_new: row.target_new.clone(),
             success,
             error_msg: error_msg.clone(),
         },
+        Some(&row.action_type),
+        Some(row.id),
     )?;
 
     if let Some(message) = error_msg {
@@ -209,6 +818,205 @@ pub fn undo_last(_app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Reverses every member of `group_id` in descending `id` order. If a
+/// member fails partway through, the members already reverted are replayed
+/// forward again (newest-reverted first) so the batch stays all-or-nothing
+/// on disk, and a single summary `Undo` row is recorded either way.
+async fn undo_group(conn: &Connection, group_id: &str) -> Result<(), String> {
+    let members = group_members(conn, group_id)?;
+
+    if members.is_empty() {
+        return Err(format!("Nothing to undo for group {group_id}"));
+    }
+
+    let mut reverted = Vec::new();
+    let mut failure = None;
+
+    for member in &members {
+        match apply_reverse(member).await {
+            Ok(_) => reverted.push(member.clone()),
+            Err(error) => {
+                failure = Some(format!("Failed to undo '{}': {error}", member.link_path));
+                break;
+            }
+        }
+    }
+
+    let (success, error_msg) = if let Some(message) = failure {
+        for member in reverted.iter().rev() {
+            let _ = apply_forward(member).await;
+        }
+        (false, Some(message))
+    } else {
+        (true, None)
+    };
+
+    let summary_path = members
+        .iter()
+        .map(|member| member.link_path.as_str())
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    insert_action_row(
+        conn,
+        &ActionInput {
+            action_type: "Undo".to_string(),
+            link_path: summary_path,
+            link_type: LinkType::Symlink,
+            target_old: None,
+            target_new: None,
+            success,
+            error_msg: error_msg.clone(),
+        },
+        Some("Group"),
+        None,
+        Some(group_id),
+    )?;
+
+    if let Some(message) = error_msg {
+        return Err(message);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn undo_last(_app: AppHandle) -> Result<(), String> {
+    let conn = crate::db::open_connection()?;
+
+    let row = latest_undo_candidate(&conn)?.ok_or_else(|| "Nothing to undo".to_string())?;
+
+    match row.group_id.clone() {
+        Some(group_id) => undo_group(&conn, &group_id).await,
+        None => undo_single(&conn, row).await,
+    }
+}
+
+async fn redo_single(conn: &Connection, row: ActionRow) -> Result<(), String> {
+    let undone_action_type = row
+        .undone_action_type
+        .clone()
+        .ok_or_else(|| "Undo row is missing its recorded action type".to_string())?;
+
+    let original = ActionRow {
+        action_type: undone_action_type,
+        ..row.clone()
+    };
+
+    let result = apply_forward(&original).await;
+    let (success, error_msg) = match result {
+        Ok(_) => (true, None),
+        Err(error) => (false, Some(error)),
+    };
+
+    log_action_with_undo_link(
+        conn,
+        ActionInput {
+            action_type: "Redo".to_string(),
+            link_path: row.link_path.clone(),
+            link_type: link_type_from_text(&row.link_type),
+            target_old: row.target_old.clone(),
+            target_new: row.target_new.clone(),
+            success,
+            error_msg: error_msg.clone(),
+        },
+        Some(&original.action_type),
+        Some(row.id),
+    )?;
+
+    if let Some(message) = error_msg {
+        return Err(message);
+    }
+
+    Ok(())
+}
+
+/// Re-applies every member of `group_id` in ascending `id` order (the
+/// original creation order). Mirrors `undo_group`'s partial-failure
+/// handling: members already redone are reversed again if a later one
+/// fails, and a single summary `Redo` row is recorded either way.
+async fn redo_group(conn: &Connection, group_id: &str) -> Result<(), String> {
+    let mut members = group_members(conn, group_id)?;
+    members.reverse();
+
+    if members.is_empty() {
+        return Err(format!("Nothing to redo for group {group_id}"));
+    }
+
+    let mut reapplied = Vec::new();
+    let mut failure = None;
+
+    for member in &members {
+        match apply_forward(member).await {
+            Ok(_) => reapplied.push(member.clone()),
+            Err(error) => {
+                failure = Some(format!("Failed to redo '{}': {error}", member.link_path));
+                break;
+            }
+        }
+    }
+
+    let (success, error_msg) = if let Some(message) = failure {
+        for member in reapplied.iter().rev() {
+            let _ = apply_reverse(member).await;
+        }
+        (false, Some(message))
+    } else {
+        (true, None)
+    };
+
+    let summary_path = members
+        .iter()
+        .map(|member| member.link_path.as_str())
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    insert_action_row(
+        conn,
+        &ActionInput {
+            action_type: "Redo".to_string(),
+            link_path: summary_path,
+            link_type: LinkType::Symlink,
+            target_old: None,
+            target_new: None,
+            success,
+            error_msg: error_msg.clone(),
+        },
+        Some("Group"),
+        None,
+        Some(group_id),
+    )?;
+
+    if let Some(message) = error_msg {
+        return Err(message);
+    }
+
+    Ok(())
+}
+
+/// Re-applies the most recently undone action, symmetric to `undo_last`.
+/// Walks the action log for an `Undo` row not yet consumed by a later
+/// `Redo`; logging any real action in between clears the redo stack, since
+/// that action branched history away from what the undo restored. An
+/// `Undo` row whose `undone_action_type` is the `"Group"` sentinel redoes
+/// every member of its group as a unit.
+#[tauri::command]
+pub async fn redo_last(_app: AppHandle) -> Result<(), String> {
+    let conn = crate::db::open_connection()?;
+
+    let row = latest_redo_candidate(&conn)?.ok_or_else(|| "Nothing to redo".to_string())?;
+
+    if row.undone_action_type.as_deref() == Some("Group") {
+        let group_id = row
+            .group_id
+            .clone()
+            .ok_or_else(|| "Group undo row is missing its group id".to_string())?;
+        return redo_group(&conn, &group_id).await;
+    }
+
+    redo_single(&conn, row).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,8 +1073,8 @@ mod tests {
             .expect("load undo candidate")
             .expect("candidate exists");
 
-        assert_eq!(candidate.0, "Create");
-        assert_eq!(candidate.1, "C:\\tmp\\first");
+        assert_eq!(candidate.action_type, "Create");
+        assert_eq!(candidate.link_path, "C:\\tmp\\first");
     }
 
     #[test]
@@ -281,7 +1089,332 @@ mod tests {
             .expect("load undo candidate")
             .expect("candidate exists");
 
-        assert_eq!(candidate.0, "Create");
-        assert_eq!(candidate.1, "C:\\tmp\\first");
+        assert_eq!(candidate.action_type, "Create");
+        assert_eq!(candidate.link_path, "C:\\tmp\\first");
+    }
+
+    #[test]
+    fn undo_candidate_is_restored_by_a_redo() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        let create_id =
+            log_action(&conn, action("Create", "C:\\tmp\\first", true)).expect("insert action");
+        log_action_with_undo_link(
+            &conn,
+            action("Undo", "C:\\tmp\\first", true),
+            Some("Create"),
+            Some(create_id),
+        )
+        .expect("insert undo action");
+        log_action_with_undo_link(
+            &conn,
+            action("Redo", "C:\\tmp\\first", true),
+            Some("Create"),
+            Some(create_id),
+        )
+        .expect("insert redo action");
+
+        let candidate = latest_undo_candidate(&conn)
+            .expect("load undo candidate")
+            .expect("candidate exists");
+
+        assert_eq!(candidate.action_type, "Create");
+        assert_eq!(candidate.link_path, "C:\\tmp\\first");
+    }
+
+    #[test]
+    fn undo_candidate_skips_irreversible_actions() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        log_action(&conn, action("Create", "C:\\tmp\\first", true)).expect("insert create");
+        log_action(&conn, action("SetPermissions", "C:\\tmp\\first", true)).expect("insert set perms");
+
+        let candidate = latest_undo_candidate(&conn)
+            .expect("load undo candidate")
+            .expect("candidate exists");
+
+        assert_eq!(candidate.action_type, "Create");
+    }
+
+    #[test]
+    fn undo_candidate_for_a_group_member_carries_its_group_id() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        let group_id = begin_batch();
+        log_grouped_action(&conn, action("Create", "C:\\tmp\\first", true), &group_id).expect("insert first action");
+        log_grouped_action(&conn, action("Create", "C:\\tmp\\second", true), &group_id).expect("insert second action");
+
+        let candidate = latest_undo_candidate(&conn)
+            .expect("load undo candidate")
+            .expect("candidate exists");
+
+        assert_eq!(candidate.group_id.as_deref(), Some(group_id.as_str()));
+    }
+
+    #[test]
+    fn a_non_grouped_action_has_no_group_id() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        log_action(&conn, action("Create", "C:\\tmp\\first", true)).expect("insert action");
+
+        let candidate = latest_undo_candidate(&conn)
+            .expect("load undo candidate")
+            .expect("candidate exists");
+
+        assert!(candidate.group_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn undo_group_logs_a_single_summary_row_for_the_whole_batch() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        let group_id = begin_batch();
+        log_grouped_action(&conn, action("Create", "C:\\tmp\\first", true), &group_id).expect("insert first action");
+        log_grouped_action(&conn, action("Create", "C:\\tmp\\second", true), &group_id).expect("insert second action");
+
+        // Both members are `Create`s, so reversing them (deleting links that
+        // this test never actually created on disk) fails;
+        // what matters here is that exactly one summary `Undo` row is
+        // logged for the whole group rather than one per member.
+        let _ = undo_group(&conn, &group_id).await;
+
+        let undo_rows: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM actions WHERE action_type = 'Undo' AND group_id = ?1",
+                params![group_id],
+                |row| row.get(0),
+            )
+            .expect("count undo rows");
+
+        assert_eq!(undo_rows, 1);
+    }
+
+    #[test]
+    fn redo_candidate_is_none_without_a_pending_undo() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        log_action(&conn, action("Create", "C:\\tmp\\first", true)).expect("insert action");
+
+        assert!(latest_redo_candidate(&conn)
+            .expect("load redo candidate")
+            .is_none());
+    }
+
+    #[test]
+    fn redo_candidate_finds_the_undone_action() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        let create_id =
+            log_action(&conn, action("Create", "C:\\tmp\\first", true)).expect("insert action");
+        log_action_with_undo_link(
+            &conn,
+            action("Undo", "C:\\tmp\\first", true),
+            Some("Create"),
+            Some(create_id),
+        )
+        .expect("insert undo action");
+
+        let candidate = latest_redo_candidate(&conn)
+            .expect("load redo candidate")
+            .expect("candidate exists");
+
+        assert_eq!(candidate.undone_action_type.as_deref(), Some("Create"));
+        assert_eq!(candidate.link_path, "C:\\tmp\\first");
+    }
+
+    #[test]
+    fn redo_candidate_is_cleared_by_a_new_action_after_the_undo() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        let create_id =
+            log_action(&conn, action("Create", "C:\\tmp\\first", true)).expect("insert action");
+        log_action_with_undo_link(
+            &conn,
+            action("Undo", "C:\\tmp\\first", true),
+            Some("Create"),
+            Some(create_id),
+        )
+        .expect("insert undo action");
+        log_action(&conn, action("Create", "C:\\tmp\\second", true)).expect("insert new action");
+
+        assert!(latest_redo_candidate(&conn)
+            .expect("load redo candidate")
+            .is_none());
+    }
+
+    #[test]
+    fn redo_candidate_skips_undo_rows_already_consumed_by_redo() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        let first_id =
+            log_action(&conn, action("Create", "C:\\tmp\\first", true)).expect("insert action");
+        log_action_with_undo_link(
+            &conn,
+            action("Undo", "C:\\tmp\\first", true),
+            Some("Create"),
+            Some(first_id),
+        )
+        .expect("insert undo action");
+        log_action_with_undo_link(
+            &conn,
+            action("Redo", "C:\\tmp\\first", true),
+            Some("Create"),
+            Some(first_id),
+        )
+        .expect("insert redo action");
+
+        assert!(latest_redo_candidate(&conn)
+            .expect("load redo candidate")
+            .is_none());
+    }
+
+    #[test]
+    fn log_grouped_action_tags_the_row_and_log_action_leaves_it_ungrouped() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        let group_id = begin_batch();
+        log_grouped_action(&conn, action("Create", "C:\\tmp\\first", true), &group_id)
+            .expect("insert grouped action");
+        log_action(&conn, action("Create", "C:\\tmp\\second", true)).expect("insert ungrouped action");
+
+        let grouped: Option<String> = conn
+            .query_row(
+                "SELECT group_id FROM actions WHERE link_path = 'C:\\tmp\\first'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("read grouped row");
+        let ungrouped: Option<String> = conn
+            .query_row(
+                "SELECT group_id FROM actions WHERE link_path = 'C:\\tmp\\second'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("read ungrouped row");
+
+        assert_eq!(grouped.as_deref(), Some(group_id.as_str()));
+        assert!(ungrouped.is_none());
+    }
+
+    #[test]
+    fn stream_all_actions_visits_every_row_newest_first() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        log_action(&conn, action("Create", "C:\\tmp\\a", true)).expect("log first action");
+        log_action(&conn, action("Create", "C:\\tmp\\b", true)).expect("log second action");
+
+        let mut paths = Vec::new();
+        stream_all_actions(&conn, |record| {
+            paths.push(record.link_path);
+            Ok(())
+        })
+        .expect("stream actions");
+
+        assert_eq!(paths, vec!["C:\\tmp\\b".to_string(), "C:\\tmp\\a".to_string()]);
+    }
+
+    #[test]
+    fn validate_action_type_rejects_unknown_values() {
+        assert!(validate_action_type("Create").is_ok());
+        assert!(validate_action_type("Teleport").is_err());
+    }
+
+    #[test]
+    fn parse_link_type_strict_rejects_unknown_values() {
+        assert!(matches!(parse_link_type_strict("Junction"), Ok(LinkType::Junction)));
+        assert!(parse_link_type_strict("Shortcut").is_err());
+    }
+
+    #[test]
+    fn insert_imported_actions_round_trips_exported_rows() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        log_action(&conn, action("Create", "C:\\tmp\\a", true)).expect("log action");
+
+        let mut records = Vec::new();
+        stream_all_actions(&conn, |record| {
+            records.push(record);
+            Ok(())
+        })
+        .expect("stream actions");
+
+        let rows: Vec<ImportRow> = records
+            .into_iter()
+            .map(|record| ImportRow {
+                action_type: record.action_type,
+                link_path: record.link_path,
+                link_type: link_type_to_text(&record.link_type).to_string(),
+                target_old: record.target_old,
+                target_new: record.target_new,
+                timestamp: record.timestamp,
+                success: record.success,
+                error_msg: record.error_msg,
+            })
+            .collect();
+
+        let other_conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&other_conn).expect("run migrations");
+
+        let imported = insert_imported_actions(&other_conn, rows).expect("import rows");
+        assert_eq!(imported, 1);
+
+        let count: i64 = other_conn
+            .query_row("SELECT COUNT(*) FROM actions", [], |row| row.get(0))
+            .expect("read count");
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn insert_imported_actions_skips_rows_already_present() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        let row = ImportRow {
+            action_type: "Create".to_string(),
+            link_path: "C:\\tmp\\a".to_string(),
+            link_type: "Symlink".to_string(),
+            target_old: None,
+            target_new: Some("C:\\tmp\\b".to_string()),
+            timestamp: "2025-01-01T00:00:00+00:00".to_string(),
+            success: true,
+            error_msg: None,
+        };
+
+        let first = insert_imported_actions(&conn, vec![row.clone()]).expect("first import");
+        let second = insert_imported_actions(&conn, vec![row]).expect("second import");
+
+        assert_eq!(first, 1);
+        assert_eq!(second, 0);
+    }
+
+    #[test]
+    fn insert_imported_actions_rejects_unknown_action_type() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+        crate::db::migrations::run(&conn).expect("run migrations");
+
+        let row = ImportRow {
+            action_type: "Teleport".to_string(),
+            link_path: "C:\\tmp\\a".to_string(),
+            link_type: "Symlink".to_string(),
+            target_old: None,
+            target_new: Some("C:\\tmp\\b".to_string()),
+            timestamp: "2025-01-01T00:00:00+00:00".to_string(),
+            success: true,
+            error_msg: None,
+        };
+
+        assert!(insert_imported_actions(&conn, vec![row]).is_err());
     }
 }
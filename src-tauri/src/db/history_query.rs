@@ -0,0 +1,391 @@
+//! A tiny filter DSL for `search_history`: `field op "value"` comparisons
+//! joined with `AND`/`OR` and grouped with parentheses, e.g.
+//! `type = "Delete" AND (path ~ "node_modules" OR date > "2026-01-01")`.
+//! Lexing, parsing, and lowering to SQL are kept as separate passes so a
+//! malformed query fails with a specific error instead of silently
+//! matching everything.
+
+use rusqlite::types::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Like,
+    Lt,
+    Gt,
+}
+
+impl Op {
+    fn as_sql(self) -> &'static str {
+        match self {
+            Op::Eq => "=",
+            Op::Ne => "!=",
+            Op::Like => "LIKE",
+            Op::Lt => "<",
+            Op::Gt => ">",
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Op::Eq => "=",
+            Op::Ne => "!=",
+            Op::Like => "~",
+            Op::Lt => "<",
+            Op::Gt => ">",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Field(String),
+    Op(Op),
+    String(String),
+    And,
+    Or,
+    LParen,
+    RParen,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '=' => {
+                tokens.push(Token::Op(Op::Eq));
+                i += 1;
+            }
+            '~' => {
+                tokens.push(Token::Op(Op::Like));
+                i += 1;
+            }
+            '<' => {
+                tokens.push(Token::Op(Op::Lt));
+                i += 1;
+            }
+            '>' => {
+                tokens.push(Token::Op(Op::Gt));
+                i += 1;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op(Op::Ne));
+                i += 2;
+            }
+            '"' => {
+                let (literal, next) = read_string_literal(&chars, i + 1)?;
+                tokens.push(Token::String(literal));
+                i = next;
+            }
+            _ if c.is_alphanumeric() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                tokens.push(match word.to_ascii_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    _ => Token::Field(word),
+                });
+            }
+            other => return Err(format!("Unexpected character '{other}' in query")),
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn read_string_literal(chars: &[char], start: usize) -> Result<(String, usize), String> {
+    let mut value = String::new();
+    let mut i = start;
+
+    while i < chars.len() {
+        match chars[i] {
+            '"' => return Ok((value, i + 1)),
+            '\\' if chars.get(i + 1) == Some(&'"') => {
+                value.push('"');
+                i += 2;
+            }
+            ch => {
+                value.push(ch);
+                i += 1;
+            }
+        }
+    }
+
+    Err("Unterminated string literal in query".to_string())
+}
+
+#[derive(Debug, Clone)]
+enum Ast {
+    Comparison { field: String, op: Op, value: String },
+    And(Box<Ast>, Box<Ast>),
+    Or(Box<Ast>, Box<Ast>),
+    Group(Box<Ast>),
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Ast, String> {
+        let mut node = self.parse_and()?;
+
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.pos += 1;
+            let rhs = self.parse_and()?;
+            node = Ast::Or(Box::new(node), Box::new(rhs));
+        }
+
+        Ok(node)
+    }
+
+    fn parse_and(&mut self) -> Result<Ast, String> {
+        let mut node = self.parse_unary()?;
+
+        while matches!(self.peek(), Some(Token::And)) {
+            self.pos += 1;
+            let rhs = self.parse_unary()?;
+            node = Ast::And(Box::new(node), Box::new(rhs));
+        }
+
+        Ok(node)
+    }
+
+    fn parse_unary(&mut self) -> Result<Ast, String> {
+        match self.peek() {
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let inner = self.parse_or()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(Ast::Group(Box::new(inner))),
+                    _ => Err("Expected a closing ')' in query".to_string()),
+                }
+            }
+            Some(Token::Field(_)) => self.parse_comparison(),
+            Some(other) => Err(format!("Expected a field or '(' but found {other:?}")),
+            None => Err("Unexpected end of query".to_string()),
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<Ast, String> {
+        let field = match self.advance() {
+            Some(Token::Field(name)) => name.clone(),
+            _ => return Err("Expected a field name in query".to_string()),
+        };
+
+        let op = match self.advance() {
+            Some(Token::Op(op)) => *op,
+            _ => return Err(format!("Expected a comparison operator after field '{field}'")),
+        };
+
+        let value = match self.advance() {
+            Some(Token::String(value)) => value.clone(),
+            _ => return Err(format!("Expected a quoted string literal after '{field} {}'", op.as_str())),
+        };
+
+        Ok(Ast::Comparison { field, op, value })
+    }
+}
+
+fn parse(query: &str) -> Result<Ast, String> {
+    let tokens = tokenize(query)?;
+    if tokens.is_empty() {
+        return Err("Query is empty".to_string());
+    }
+
+    let mut parser = Parser { tokens: &tokens, pos: 0 };
+    let ast = parser.parse_or()?;
+
+    if parser.pos != parser.tokens.len() {
+        return Err(format!(
+            "Unexpected token after expression: {:?}",
+            parser.tokens[parser.pos]
+        ));
+    }
+
+    Ok(ast)
+}
+
+fn literal_value(op: Op, value: &str) -> Value {
+    match op {
+        Op::Like => Value::Text(format!("%{value}%")),
+        _ => Value::Text(value.to_string()),
+    }
+}
+
+fn lower_column(
+    field: &str,
+    column: &str,
+    op: Op,
+    value: &str,
+    allowed: &[Op],
+    params: &mut Vec<Value>,
+) -> Result<String, String> {
+    if !allowed.contains(&op) {
+        return Err(format!("Operator '{}' is not supported for field '{field}'", op.as_str()));
+    }
+
+    params.push(literal_value(op, value));
+    Ok(format!("{column} {} ?", op.as_sql()))
+}
+
+fn lower_success(op: Op, value: &str, params: &mut Vec<Value>) -> Result<String, String> {
+    if !matches!(op, Op::Eq | Op::Ne) {
+        return Err(format!("Operator '{}' is not supported for field 'success'", op.as_str()));
+    }
+
+    let flag = match value.to_ascii_lowercase().as_str() {
+        "true" | "1" => 1,
+        "false" | "0" => 0,
+        other => return Err(format!("'success' expects true/false, got '{other}'")),
+    };
+
+    params.push(Value::Integer(flag));
+    Ok(format!("success {} ?", op.as_sql()))
+}
+
+fn lower_target(op: Op, value: &str, params: &mut Vec<Value>) -> Result<String, String> {
+    if !matches!(op, Op::Eq | Op::Ne | Op::Like) {
+        return Err(format!("Operator '{}' is not supported for field 'target'", op.as_str()));
+    }
+
+    let literal = literal_value(op, value);
+    params.push(literal.clone());
+    params.push(literal);
+    Ok(format!("(target_old {0} ? OR target_new {0} ?)", op.as_sql()))
+}
+
+fn lower_comparison(field: &str, op: Op, value: &str, params: &mut Vec<Value>) -> Result<String, String> {
+    match field {
+        "type" => lower_column(field, "action_type", op, value, &[Op::Eq, Op::Ne, Op::Like], params),
+        "path" => lower_column(field, "link_path", op, value, &[Op::Eq, Op::Ne, Op::Like], params),
+        "target" => lower_target(op, value, params),
+        "success" => lower_success(op, value, params),
+        "date" => lower_column(field, "timestamp", op, value, &[Op::Eq, Op::Ne, Op::Lt, Op::Gt], params),
+        other => Err(format!(
+            "Unknown field '{other}' (expected one of: type, path, target, success, date)"
+        )),
+    }
+}
+
+fn lower(ast: &Ast, params: &mut Vec<Value>) -> Result<String, String> {
+    match ast {
+        Ast::Comparison { field, op, value } => lower_comparison(field, *op, value, params),
+        Ast::And(lhs, rhs) => Ok(format!("({} AND {})", lower(lhs, params)?, lower(rhs, params)?)),
+        Ast::Or(lhs, rhs) => Ok(format!("({} OR {})", lower(lhs, params)?, lower(rhs, params)?)),
+        Ast::Group(inner) => Ok(format!("({})", lower(inner, params)?)),
+    }
+}
+
+/// Compiles a filter query into a parameterized SQL `WHERE` clause and its
+/// bound parameters. Every literal in `query` ends up as a `Value` in the
+/// returned vec rather than interpolated into the clause.
+pub(crate) fn compile(query: &str) -> Result<(String, Vec<Value>), String> {
+    let ast = parse(query)?;
+    let mut params = Vec::new();
+    let clause = lower(&ast, &mut params)?;
+    Ok((clause, params))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_a_simple_equality() {
+        let (clause, params) = compile("type = \"Delete\"").expect("compile query");
+        assert_eq!(clause, "action_type = ?");
+        assert_eq!(params, vec![Value::Text("Delete".to_string())]);
+    }
+
+    #[test]
+    fn substring_operator_wraps_value_for_like() {
+        let (clause, params) = compile("path ~ \"node_modules\"").expect("compile query");
+        assert_eq!(clause, "link_path LIKE ?");
+        assert_eq!(params, vec![Value::Text("%node_modules%".to_string())]);
+    }
+
+    #[test]
+    fn target_matches_either_column() {
+        let (clause, params) = compile("target = \"C:\\\\tmp\\\\a\"").expect("compile query");
+        assert_eq!(clause, "(target_old = ? OR target_new = ?)");
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn and_or_and_parentheses_compose() {
+        let (clause, _params) = compile(
+            "type = \"Delete\" AND (path ~ \"tmp\" OR date > \"2026-01-01\")",
+        )
+        .expect("compile query");
+
+        assert_eq!(
+            clause,
+            "(action_type = ? AND ((link_path LIKE ? OR timestamp > ?)))"
+        );
+    }
+
+    #[test]
+    fn success_accepts_boolean_words() {
+        let (clause, params) = compile("success = \"true\"").expect("compile query");
+        assert_eq!(clause, "success = ?");
+        assert_eq!(params, vec![Value::Integer(1)]);
+    }
+
+    #[test]
+    fn unknown_field_is_a_descriptive_error() {
+        let error = compile("bogus = \"x\"").expect_err("should reject unknown field");
+        assert!(error.contains("Unknown field 'bogus'"));
+    }
+
+    #[test]
+    fn unsupported_operator_for_field_is_rejected() {
+        let error = compile("path > \"x\"").expect_err("should reject '>' on path");
+        assert!(error.contains("not supported for field 'path'"));
+    }
+
+    #[test]
+    fn unterminated_string_is_rejected() {
+        let error = compile("type = \"Delete").expect_err("should reject unterminated string");
+        assert!(error.contains("Unterminated string literal"));
+    }
+
+    #[test]
+    fn missing_operator_is_rejected() {
+        let error = compile("type \"Delete\"").expect_err("should reject missing operator");
+        assert!(error.contains("Expected a comparison operator"));
+    }
+}
@@ -2,31 +2,139 @@ use std::collections::HashSet;
 
 use rusqlite::{Connection, OptionalExtension};
 
-const TARGET_USER_VERSION: i64 = 1;
+struct Migration {
+    version: i64,
+    name: &'static str,
+    apply: fn(&Connection) -> Result<(), String>,
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "reconcile_actions_and_scan_jobs",
+        apply: migration_1_reconcile_actions_and_scan_jobs,
+    },
+    Migration {
+        version: 2,
+        name: "create_work_jobs",
+        apply: migration_2_create_work_jobs,
+    },
+    Migration {
+        version: 3,
+        name: "add_undo_redo_columns",
+        apply: migration_3_add_undo_redo_columns,
+    },
+    Migration {
+        version: 4,
+        name: "add_action_group_id",
+        apply: migration_4_add_action_group_id,
+    },
+];
 
 pub fn run(conn: &Connection) -> Result<(), String> {
-    let tx = conn
-        .unchecked_transaction()
-        .map_err(|e| format!("Failed to start migration transaction: {e}"))?;
+    let current_version = conn
+        .pragma_query_value(None, "user_version", |row| row.get::<_, i64>(0))
+        .map_err(|e| format!("Migration failed while reading schema version: {e}"))?;
+
+    let mut pending: Vec<&Migration> = MIGRATIONS
+        .iter()
+        .filter(|migration| migration.version > current_version)
+        .collect();
+    pending.sort_by_key(|migration| migration.version);
+
+    for migration in pending {
+        let tx = conn.unchecked_transaction().map_err(|e| {
+            format!(
+                "Failed to start transaction for migration {} ({}): {e}",
+                migration.version, migration.name
+            )
+        })?;
+
+        (migration.apply)(&tx).map_err(|e| {
+            format!("Migration {} ({}) failed: {e}", migration.version, migration.name)
+        })?;
+
+        tx.pragma_update(None, "user_version", migration.version)
+            .map_err(|e| {
+                format!(
+                    "Failed to record schema version after migration {} ({}): {e}",
+                    migration.version, migration.name
+                )
+            })?;
+
+        tx.commit().map_err(|e| {
+            format!("Failed to commit migration {} ({}): {e}", migration.version, migration.name)
+        })?;
+    }
+
+    Ok(())
+}
 
-    if !table_exists(&tx, "actions")? {
-        create_actions_table(&tx)?;
+fn migration_1_reconcile_actions_and_scan_jobs(conn: &Connection) -> Result<(), String> {
+    if !table_exists(conn, "actions")? {
+        create_actions_table(conn)?;
     } else {
-        ensure_actions_schema(&tx)?;
+        ensure_actions_schema(conn)?;
+    }
+
+    if !table_exists(conn, "scan_jobs")? {
+        create_scan_jobs_table(conn)?;
     }
 
-    tx.execute_batch(
+    conn.execute_batch(
         "
         CREATE INDEX IF NOT EXISTS idx_actions_success_id ON actions(success, id DESC);
         ",
     )
-    .map_err(|e| format!("Migration failed while creating indexes: {e}"))?;
+    .map_err(|e| format!("Migration failed while creating indexes: {e}"))
+}
+
+fn migration_2_create_work_jobs(conn: &Connection) -> Result<(), String> {
+    if !table_exists(conn, "work_jobs")? {
+        create_work_jobs_table(conn)?;
+    }
+
+    Ok(())
+}
 
-    tx.pragma_update(None, "user_version", TARGET_USER_VERSION)
-        .map_err(|e| format!("Migration failed while updating schema version: {e}"))?;
+fn migration_3_add_undo_redo_columns(conn: &Connection) -> Result<(), String> {
+    let mut stmt = conn
+        .prepare("PRAGMA table_info(actions)")
+        .map_err(|e| format!("Migration failed while reading actions schema: {e}"))?;
+
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|e| format!("Migration failed while decoding actions schema: {e}"))?
+        .collect::<Result<HashSet<_>, _>>()
+        .map_err(|e| format!("Migration failed while collecting actions schema: {e}"))?;
+
+    add_column_if_missing(conn, &columns, "undone_action_type", "TEXT")?;
+    add_column_if_missing(conn, &columns, "undone_action_id", "INTEGER")?;
 
-    tx.commit()
-        .map_err(|e| format!("Failed to commit migrations: {e}"))
+    Ok(())
+}
+
+fn migration_4_add_action_group_id(conn: &Connection) -> Result<(), String> {
+    let mut stmt = conn
+        .prepare("PRAGMA table_info(actions)")
+        .map_err(|e| format!("Migration failed while reading actions schema: {e}"))?;
+
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|e| format!("Migration failed while decoding actions schema: {e}"))?
+        .collect::<Result<HashSet<_>, _>>()
+        .map_err(|e| format!("Migration failed while collecting actions schema: {e}"))?;
+
+    add_column_if_missing(conn, &columns, "group_id", "TEXT")?;
+
+    conn.execute_batch(
+        "
+        CREATE INDEX IF NOT EXISTS idx_actions_group_id ON actions(group_id);
+        ",
+    )
+    .map_err(|e| format!("Migration failed while indexing actions.group_id: {e}"))?;
+
+    Ok(())
 }
 
 fn table_exists(conn: &Connection, table_name: &str) -> Result<bool, String> {
@@ -64,6 +172,45 @@ fn create_actions_table(conn: &Connection) -> Result<(), String> {
     .map_err(|e| format!("Migration failed while creating actions table: {e}"))
 }
 
+fn create_scan_jobs_table(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch(
+        "
+        CREATE TABLE scan_jobs (
+            id TEXT PRIMARY KEY,
+            mode TEXT NOT NULL,
+            scanned INTEGER NOT NULL DEFAULT 0,
+            found INTEGER NOT NULL DEFAULT 0,
+            current_path TEXT NOT NULL DEFAULT '',
+            checkpoint_path TEXT,
+            state TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );
+        ",
+    )
+    .map_err(|e| format!("Migration failed while creating scan_jobs table: {e}"))
+}
+
+fn create_work_jobs_table(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch(
+        "
+        CREATE TABLE work_jobs (
+            id TEXT PRIMARY KEY,
+            kind TEXT NOT NULL,
+            state TEXT NOT NULL,
+            processed INTEGER NOT NULL DEFAULT 0,
+            total INTEGER NOT NULL DEFAULT 0,
+            pending BLOB NOT NULL,
+            completed BLOB NOT NULL,
+            params BLOB NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );
+        ",
+    )
+    .map_err(|e| format!("Migration failed while creating work_jobs table: {e}"))
+}
+
 fn ensure_actions_schema(conn: &Connection) -> Result<(), String> {
     let mut stmt = conn
         .prepare("PRAGMA table_info(actions)")
@@ -163,4 +310,33 @@ mod tests {
             .expect("read migrated success flag");
         assert_eq!(success, 1);
     }
+
+    #[test]
+    fn running_migrations_twice_is_a_no_op() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+
+        run(&conn).expect("first run");
+        run(&conn).expect("second run");
+
+        let version: i64 = conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .expect("read user_version");
+
+        let expected = MIGRATIONS.iter().map(|m| m.version).max().unwrap_or(0);
+        assert_eq!(version, expected);
+    }
+
+    #[test]
+    fn fresh_database_lands_on_latest_registered_version() {
+        let conn = Connection::open_in_memory().expect("in-memory DB");
+
+        run(&conn).expect("run migrations");
+
+        let version: i64 = conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .expect("read user_version");
+
+        let expected = MIGRATIONS.iter().map(|m| m.version).max().unwrap_or(0);
+        assert_eq!(version, expected);
+    }
 }
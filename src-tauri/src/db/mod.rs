@@ -1,4 +1,5 @@
 pub mod history;
+mod history_query;
 pub mod migrations;
 
 use std::path::PathBuf;
@@ -6,6 +7,8 @@ use std::time::Duration;
 
 use rusqlite::{Connection, OpenFlags};
 
+use crate::config::load_config;
+
 pub fn db_path() -> Result<PathBuf, String> {
     let home = dirs::home_dir().ok_or_else(|| "Cannot resolve home directory".to_string())?;
     let dir = home.join("symview");
@@ -15,19 +18,16 @@ pub fn db_path() -> Result<PathBuf, String> {
     Ok(dir.join("history.db"))
 }
 
-pub fn open_connection() -> Result<Connection, String> {
-    let path = db_path()?;
-
-    let conn = Connection::open_with_flags(
-        path,
-        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE,
-    )
-    .map_err(|e| format!("Failed to open DB: {e}"))?;
+struct ConnectionOptions {
+    busy_timeout: Duration,
+}
 
-    conn.busy_timeout(Duration::from_secs(5))
+fn apply_connection_options(conn: &Connection, options: &ConnectionOptions) -> Result<(), String> {
+    conn.busy_timeout(options.busy_timeout)
         .map_err(|e| format!("Failed to set DB busy timeout: {e}"))?;
 
-    migrations::run(&conn)?;
+    conn.pragma_update(None, "busy_timeout", options.busy_timeout.as_millis() as i64)
+        .map_err(|e| format!("Failed to set DB busy_timeout pragma: {e}"))?;
 
     if let Err(wal_error) = conn.pragma_update(None, "journal_mode", "WAL") {
         conn.pragma_update(None, "journal_mode", "DELETE")
@@ -38,5 +38,73 @@ pub fn open_connection() -> Result<Connection, String> {
             })?;
     }
 
+    conn.pragma_update(None, "synchronous", "NORMAL")
+        .map_err(|e| format!("Failed to set DB synchronous mode: {e}"))?;
+
+    conn.pragma_update(None, "foreign_keys", "ON")
+        .map_err(|e| format!("Failed to enable DB foreign keys: {e}"))?;
+
+    Ok(())
+}
+
+pub fn open_connection() -> Result<Connection, String> {
+    let path = db_path()?;
+
+    let conn = Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE,
+    )
+    .map_err(|e| format!("Failed to open DB: {e}"))?;
+
+    let busy_timeout_ms = load_config()
+        .map(|config| config.db.busy_timeout_ms)
+        .unwrap_or(5_000);
+
+    apply_connection_options(
+        &conn,
+        &ConnectionOptions {
+            busy_timeout: Duration::from_millis(busy_timeout_ms),
+        },
+    )?;
+
+    migrations::run(&conn)?;
+
     Ok(conn)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wal_mode_is_active_after_applying_connection_options() {
+        let dir = std::env::temp_dir().join(format!(
+            "symview_db_test_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        let path = dir.join("wal_test.db");
+
+        let conn = Connection::open(&path).expect("open file-backed DB");
+        apply_connection_options(
+            &conn,
+            &ConnectionOptions {
+                busy_timeout: Duration::from_millis(1_000),
+            },
+        )
+        .expect("apply connection options");
+
+        let mode: String = conn
+            .pragma_query_value(None, "journal_mode", |row| row.get(0))
+            .expect("read journal_mode");
+
+        assert_eq!(mode.to_lowercase(), "wal");
+
+        drop(conn);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,39 +1,91 @@
-use std::process::Command;
+use std::os::windows::ffi::OsStrExt;
 
-fn ps_single_quoted(value: &str) -> String {
-    format!("'{}'", value.replace('\'', "''"))
+use windows_sys::Win32::Foundation::{CloseHandle, GetLastError, HANDLE};
+use windows_sys::Win32::Security::{
+    GetTokenInformation, OpenProcessToken, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY,
+};
+use windows_sys::Win32::System::Threading::GetCurrentProcess;
+use windows_sys::Win32::UI::Shell::{ShellExecuteExW, SEE_MASK_NOCLOSEPROCESS, SHELLEXECUTEINFOW};
+use windows_sys::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+fn to_wide_null(value: &str) -> Vec<u16> {
+    std::ffi::OsStr::new(value)
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect()
 }
 
-fn ps_argument_list(args: &[String]) -> String {
-    if args.is_empty() {
-        return String::new();
+struct OwnedHandle(HANDLE);
+
+impl Drop for OwnedHandle {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe {
+                let _ = CloseHandle(self.0);
+            }
+        }
     }
+}
 
-    let escaped = args
-        .iter()
-        .map(|arg| ps_single_quoted(arg))
-        .collect::<Vec<_>>()
-        .join(", ");
+/// Quotes a single argument using the same backslash/quote rules as the
+/// Windows C runtime's argv parser (and `CommandLineToArgvW`), so the
+/// re-launched process sees back exactly the arguments it was given.
+fn quote_windows_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.chars().any(|c| c == ' ' || c == '\t' || c == '"') {
+        return arg.to_string();
+    }
 
-    format!(" -ArgumentList @({escaped})")
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0_usize;
+
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+fn build_argument_string(args: &[String]) -> String {
+    args.iter()
+        .map(|arg| quote_windows_arg(arg))
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 #[tauri::command]
 pub fn is_elevated() -> bool {
-    let output = Command::new("powershell")
-        .args([
-            "-NoProfile",
-            "-NonInteractive",
-            "-Command",
-            "(New-Object Security.Principal.WindowsPrincipal([Security.Principal.WindowsIdentity]::GetCurrent())).IsInRole([Security.Principal.WindowsBuiltInRole]::Administrator)",
-        ])
-        .output();
-
-    match output {
-        Ok(result) => String::from_utf8_lossy(&result.stdout)
-            .trim()
-            .eq_ignore_ascii_case("true"),
-        Err(_) => false,
+    unsafe {
+        let mut token: HANDLE = std::ptr::null_mut();
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) == 0 {
+            return false;
+        }
+        let token = OwnedHandle(token);
+
+        let mut elevation: TOKEN_ELEVATION = std::mem::zeroed();
+        let mut returned_len = 0_u32;
+        let ok = GetTokenInformation(
+            token.0,
+            TokenElevation,
+            &mut elevation as *mut TOKEN_ELEVATION as *mut _,
+            std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+            &mut returned_len,
+        );
+
+        ok != 0 && elevation.TokenIsElevated != 0
     }
 }
 
@@ -43,27 +95,34 @@ pub fn relaunch_as_admin() -> Result<(), String> {
     let exe_str = exe
         .to_str()
         .ok_or_else(|| "Invalid executable path".to_string())?;
-    let current_args: Vec<String> = std::env::args().skip(1).collect();
-    let command = format!(
-        "$ErrorActionPreference = 'Stop'; Start-Process -FilePath {} -Verb RunAs{}",
-        ps_single_quoted(exe_str),
-        ps_argument_list(&current_args)
-    );
-
-    let status = Command::new("powershell")
-        .args(["-NoProfile", "-NonInteractive", "-Command", &command])
-        .status()
-        .map_err(|e| format!("Failed to relaunch as admin: {e}"))?;
-
-    if !status.success() {
-        return Err(format!(
-            "Failed to relaunch as admin (PowerShell exit code: {})",
-            status
-                .code()
-                .map(|code| code.to_string())
-                .unwrap_or_else(|| "unknown".to_string())
-        ));
+
+    let parameters = build_argument_string(&std::env::args().skip(1).collect::<Vec<_>>());
+
+    let verb = to_wide_null("runas");
+    let file = to_wide_null(exe_str);
+    let params = to_wide_null(&parameters);
+
+    let mut info: SHELLEXECUTEINFOW = unsafe { std::mem::zeroed() };
+    info.cbSize = std::mem::size_of::<SHELLEXECUTEINFOW>() as u32;
+    info.fMask = SEE_MASK_NOCLOSEPROCESS;
+    info.lpVerb = verb.as_ptr();
+    info.lpFile = file.as_ptr();
+    info.lpParameters = if parameters.is_empty() {
+        std::ptr::null()
+    } else {
+        params.as_ptr()
+    };
+    info.nShow = SW_SHOWNORMAL;
+
+    let launched = unsafe { ShellExecuteExW(&mut info) };
+
+    if launched == 0 {
+        return Err(format!("Failed to relaunch as admin: {}", unsafe {
+            GetLastError()
+        }));
     }
 
+    let _ = OwnedHandle(info.hProcess);
+
     std::process::exit(0);
 }
@@ -1,9 +1,11 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod bench;
 mod commands;
 mod config;
 mod db;
 mod elevation;
+mod policy;
 mod types;
 
 use tauri::Emitter;
@@ -20,10 +22,64 @@ fn startup_path_arg() -> Option<String> {
     None
 }
 
+/// `--bench <workload.json>` is a gated, headless entry point: it runs one
+/// workload through `crate::bench` and appends the result to a report file
+/// instead of launching the GUI. `--report <path>` overrides the default
+/// report location (`bench-report.json` next to the workload file).
+fn bench_args() -> Option<(String, Option<String>)> {
+    let mut args = std::env::args();
+    let mut workload = None;
+    let mut report = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bench" => workload = args.next(),
+            "--report" => report = args.next(),
+            _ => {}
+        }
+    }
+
+    workload.map(|workload| (workload, report))
+}
+
+fn run_bench_and_exit(workload_path: String, report_path: Option<String>) -> ! {
+    let workload_path = std::path::PathBuf::from(workload_path);
+    let report_path = report_path
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| workload_path.with_file_name("bench-report.json"));
+
+    let runtime = tokio::runtime::Runtime::new().expect("failed to start bench runtime");
+    let outcome = runtime.block_on(bench::run_workload_file(&workload_path, &report_path));
+
+    match outcome {
+        Ok(result) => {
+            println!(
+                "{} ({}): {} links in {}ms ({:.1} links/sec, concurrency {})",
+                result.workload,
+                result.operation,
+                result.link_count,
+                result.wall_clock_ms,
+                result.throughput_links_per_sec,
+                result.realized_concurrency,
+            );
+            std::process::exit(0);
+        }
+        Err(error) => {
+            eprintln!("bench run failed: {error}");
+            std::process::exit(1);
+        }
+    }
+}
+
 fn main() {
+    if let Some((workload_path, report_path)) = bench_args() {
+        run_bench_and_exit(workload_path, report_path);
+    }
+
     tauri::Builder::default()
         .setup(|app| {
             db::open_connection()?;
+            commands::work_jobs::resume_interrupted_jobs_on_startup()?;
 
             if let Some(path) = startup_path_arg() {
                 let _ = app.emit("startup:path", path);
@@ -34,13 +90,39 @@ fn main() {
         .invoke_handler(tauri::generate_handler![
             commands::volumes::list_volumes,
             commands::scan::scan_volume,
+            commands::scan::scan_all_volumes,
             commands::validate::validate_links,
             commands::links::create_link,
             commands::links::delete_link,
             commands::links::retarget_link,
+            commands::links::create_links,
+            commands::links::delete_links,
+            commands::links::retarget_links,
+            commands::links::set_link_attributes,
             commands::links::open_target,
             commands::details::get_link_details,
+            commands::manifest::create_manifest,
+            commands::manifest::restore_manifest,
             commands::export::export_links,
+            commands::export::export_scan,
+            commands::archive::export_scan_archive,
+            commands::archive::import_scan_archive,
+            commands::sweep::sweep_broken_links,
+            commands::repair::dump_links,
+            commands::repair::repair_links,
+            commands::jobs::start_scan_job,
+            commands::jobs::pause_scan,
+            commands::jobs::resume_scan,
+            commands::jobs::cancel_scan,
+            commands::jobs::list_scan_jobs,
+            commands::work_jobs::start_export_job,
+            commands::work_jobs::start_validate_job,
+            commands::work_jobs::pause_job,
+            commands::work_jobs::resume_job,
+            commands::work_jobs::cancel_job,
+            commands::work_jobs::list_jobs,
+            commands::watch::start_watch,
+            commands::watch::stop_watch,
             commands::shell::register_shell_integration,
             commands::shell::unregister_shell_integration,
             commands::shell::is_shell_integration_registered,
@@ -49,7 +131,11 @@ fn main() {
             elevation::is_elevated,
             elevation::relaunch_as_admin,
             db::history::get_history,
+            db::history::search_history,
             db::history::undo_last,
+            db::history::redo_last,
+            db::history::export_history,
+            db::history::import_history,
         ])
         .run(tauri::generate_context!())
         .expect("error while running symview application");
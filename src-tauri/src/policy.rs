@@ -0,0 +1,125 @@
+use std::sync::OnceLock;
+
+use mlua::{Lua, Value};
+
+use crate::config::load_config;
+use crate::types::{LinkEntry, LinkStatus};
+
+/// Compiles the user's policy script (if configured) to Lua bytecode once
+/// and caches it, so repeated validations don't re-parse the source on
+/// every call. `Ok(None)` means no script is configured (or the config
+/// itself couldn't be loaded, which is the same as not opting in); `Err`
+/// means a script *was* configured but couldn't be read or failed to
+/// compile, which the caller surfaces rather than silently treating as
+/// "no policy" — a typo'd script shouldn't disable enforcement.
+fn compiled_policy_script() -> &'static Result<Option<Vec<u8>>, String> {
+    static COMPILED: OnceLock<Result<Option<Vec<u8>>, String>> = OnceLock::new();
+    COMPILED.get_or_init(|| {
+        let Ok(config) = load_config() else {
+            return Ok(None);
+        };
+        let Some(script_path) = config.policy.script_path else {
+            return Ok(None);
+        };
+        let source = std::fs::read_to_string(&script_path)
+            .map_err(|e| format!("Failed to read policy script {script_path}: {e}"))?;
+
+        let lua = Lua::new();
+        let function = lua
+            .load(&source)
+            .into_function()
+            .map_err(|e| format!("Failed to compile policy script {script_path}: {e}"))?;
+
+        Ok(Some(function.dump(false)))
+    })
+}
+
+fn status_label(status: &LinkStatus) -> String {
+    match status {
+        LinkStatus::Ok => "Ok".to_string(),
+        LinkStatus::AccessDenied => "AccessDenied".to_string(),
+        LinkStatus::Recursive => "Recursive".to_string(),
+        LinkStatus::Cyclic => "Cyclic".to_string(),
+        LinkStatus::Broken(reason) => format!("Broken({reason})"),
+        LinkStatus::PolicyViolation(reason) => format!("PolicyViolation({reason})"),
+    }
+}
+
+/// Runs the compiled policy script (if any) against `entry`/`status`,
+/// returning `Some(reason)` when the script flags a violation. The caller
+/// is expected to run this inside `spawn_blocking` under the same 500ms
+/// timeout budget used for metadata checks, since a runaway script should
+/// not be able to hang the validation loop.
+pub(crate) fn evaluate_policy(
+    entry: &LinkEntry,
+    resolved_target: &str,
+    status: &LinkStatus,
+) -> Result<Option<String>, String> {
+    let bytecode = match compiled_policy_script() {
+        Ok(Some(bytecode)) => bytecode,
+        Ok(None) => return Ok(None),
+        Err(error) => return Err(error.clone()),
+    };
+
+    let lua = Lua::new();
+    let function = lua
+        .load(bytecode.as_slice())
+        .set_name("symview_policy")
+        .into_function()
+        .map_err(|e| format!("Failed to load policy script bytecode: {e}"))?;
+
+    let table = lua
+        .create_table()
+        .map_err(|e| format!("Failed to build policy input table: {e}"))?;
+    table
+        .set("path", entry.path.clone())
+        .map_err(|e| format!("Failed to set policy table field 'path': {e}"))?;
+    table
+        .set("target", entry.target.clone())
+        .map_err(|e| format!("Failed to set policy table field 'target': {e}"))?;
+    table
+        .set("resolved_target", resolved_target.to_string())
+        .map_err(|e| format!("Failed to set policy table field 'resolved_target': {e}"))?;
+    table
+        .set("link_type", format!("{:?}", entry.link_type))
+        .map_err(|e| format!("Failed to set policy table field 'link_type': {e}"))?;
+    table
+        .set("status", status_label(status))
+        .map_err(|e| format!("Failed to set policy table field 'status': {e}"))?;
+
+    let result: Value = function
+        .call(table)
+        .map_err(|e| format!("Policy script error: {e}"))?;
+
+    match result {
+        Value::Nil => Ok(None),
+        Value::String(reason) => Ok(Some(
+            reason
+                .to_str()
+                .map_err(|e| format!("Policy script returned invalid UTF-8: {e}"))?
+                .to_string(),
+        )),
+        other => Err(format!(
+            "Policy script must return nil or a string, got {}",
+            other.type_name()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_label_formats_each_variant() {
+        assert_eq!(status_label(&LinkStatus::Ok), "Ok");
+        assert_eq!(status_label(&LinkStatus::AccessDenied), "AccessDenied");
+        assert_eq!(status_label(&LinkStatus::Recursive), "Recursive");
+        assert_eq!(status_label(&LinkStatus::Cyclic), "Cyclic");
+        assert_eq!(status_label(&LinkStatus::Broken("x".to_string())), "Broken(x)");
+        assert_eq!(
+            status_label(&LinkStatus::PolicyViolation("x".to_string())),
+            "PolicyViolation(x)"
+        );
+    }
+}
@@ -7,6 +7,11 @@ pub struct LinkEntry {
     pub target: String,
     pub link_type: LinkType,
     pub status: LinkStatus,
+    /// Every other path name that shares this file's hardlink identity,
+    /// excluding `path` itself. Always empty for `LinkType::Symlink` and
+    /// `LinkType::Junction`, since those have exactly one name.
+    #[serde(default)]
+    pub hardlink_siblings: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +26,13 @@ pub enum LinkStatus {
     Ok,
     Broken(String),
     AccessDenied,
+    /// The reparse chain exceeded `MAX_LINK_HOPS` without ever revisiting a
+    /// hop — a pathologically long but not actually looping chain.
+    Recursive,
+    /// The reparse chain revisited a hop it had already walked, i.e. an
+    /// actual A -> B -> A style cycle rather than just a long chain.
+    Cyclic,
+    PolicyViolation(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,6 +81,30 @@ pub struct ScanResult {
     pub mode: ScanMode,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JobState {
+    Queued,
+    Running,
+    Paused,
+    Completed,
+    Failed,
+    Canceled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobReport {
+    pub id: String,
+    pub mode: ScanMode,
+    pub scanned: u64,
+    pub found: u64,
+    pub current_path: String,
+    pub checkpoint_path: Option<String>,
+    pub state: JobState,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VolumeInfo {
     pub letter: String,
@@ -82,6 +118,7 @@ pub struct VolumeInfo {
 pub enum ExportFormat {
     Csv,
     Json,
+    Ndjson,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,7 +65,7 @@ async fn symlink_scan_validate_delete_cycle() -> Result<(), Box<dyn std::error::
     let link_text = link.to_string_lossy().to_string();
     let target_text = target.to_string_lossy().to_string();
 
-    match create_link_internal(&link_text, &target_text, &LinkType::Symlink, false) {
+    match create_link_internal(&link_text, &target_text, &LinkType::Symlink, false).await {
         Ok(()) => {}
         Err(error) if error.contains("SeCreateSymbolicLinkPrivilege") => {
             eprintln!("Skipping symlink integration test: {error}");
@@ -89,7 +89,7 @@ async fn symlink_scan_validate_delete_cycle() -> Result<(), Box<dyn std::error::
         validated[0].status
     );
 
-    delete_link_internal(&link_text)?;
+    delete_link_internal(&link_text).await?;
     assert!(!link.exists(), "Link path should be removed after delete");
     assert!(target.exists(), "Target path should remain after delete");
 
@@ -112,6 +112,7 @@ async fn junction_scan_validate_delete_cycle() -> Result<(), Box<dyn std::error:
     let target_text = target_dir.to_string_lossy().to_string();
 
     create_link_internal(&link_text, &target_text, &LinkType::Junction, false)
+        .await
         .map_err(|e| format!("create junction failed: {e}"))?;
 
     let scanned = scan_path_with_walkdir_for_tests(root.to_string_lossy().as_ref())
@@ -137,7 +138,7 @@ async fn junction_scan_validate_delete_cycle() -> Result<(), Box<dyn std::error:
         fs::remove_file(&marker).map_err(|e| format!("cleanup marker failed: {e}"))?;
     }
 
-    if let Err(error) = delete_link_internal(&link_text) {
+    if let Err(error) = delete_link_internal(&link_text).await {
         if is_access_denied(&error) {
             eprintln!("Skipping junction delete assertion due access restrictions: {error}");
             remove_if_exists(&root);
@@ -169,7 +170,7 @@ async fn broken_symlink_is_scanned_and_marked_broken() -> Result<(), Box<dyn std
     let link_text = link.to_string_lossy().to_string();
     let target_text = target.to_string_lossy().to_string();
 
-    match create_link_internal(&link_text, &target_text, &LinkType::Symlink, false) {
+    match create_link_internal(&link_text, &target_text, &LinkType::Symlink, false).await {
         Ok(()) => {}
         Err(error) if error.contains("SeCreateSymbolicLinkPrivilege") => {
             eprintln!("Skipping broken symlink integration test: {error}");
@@ -202,13 +203,58 @@ async fn broken_symlink_is_scanned_and_marked_broken() -> Result<(), Box<dyn std
         validated[0].status
     );
 
-    delete_link_internal(&link_text)?;
+    delete_link_internal(&link_text).await?;
     assert!(!link.exists(), "Symlink path should be removed after delete");
 
     remove_if_exists(&root);
     Ok(())
 }
 
+#[tokio::test]
+async fn cyclic_symlinks_are_scanned_and_marked_cyclic() -> Result<(), Box<dyn std::error::Error>> {
+    let root = temp_root("recursive_symlink_cycle");
+    fs::create_dir_all(&root)?;
+
+    let link_a = root.join("a.lnk");
+    let link_b = root.join("b.lnk");
+
+    let a_text = link_a.to_string_lossy().to_string();
+    let b_text = link_b.to_string_lossy().to_string();
+
+    match create_link_internal(&a_text, &b_text, &LinkType::Symlink, false).await {
+        Ok(()) => {}
+        Err(error) if error.contains("SeCreateSymbolicLinkPrivilege") => {
+            eprintln!("Skipping recursive symlink integration test: {error}");
+            remove_if_exists(&root);
+            return Ok(());
+        }
+        Err(error) => {
+            remove_if_exists(&root);
+            return Err(error.into());
+        }
+    }
+
+    create_link_internal(&b_text, &a_text, &LinkType::Symlink, false).await?;
+
+    let scanned = scan_path_with_walkdir_for_tests(root.to_string_lossy().as_ref())?;
+    let link_entry =
+        find_entry(&link_a, &scanned).ok_or("Cyclic symlink was not found by walkdir scan")?;
+
+    let validated = validate_links(vec![link_entry]).await;
+    assert_eq!(validated.len(), 1, "Expected a single validation result");
+    assert!(
+        matches!(validated[0].status, LinkStatus::Cyclic),
+        "Expected validation status Cyclic, got {:?}",
+        validated[0].status
+    );
+
+    delete_link_internal(&a_text).await?;
+    delete_link_internal(&b_text).await?;
+
+    remove_if_exists(&root);
+    Ok(())
+}
+
 #[tokio::test]
 async fn hardlink_scan_validate_delete_cycle() -> Result<(), Box<dyn std::error::Error>> {
     let root = temp_root("hardlink_cycle");
@@ -221,7 +267,7 @@ async fn hardlink_scan_validate_delete_cycle() -> Result<(), Box<dyn std::error:
     let sibling_text = sibling.to_string_lossy().to_string();
     let original_text = original.to_string_lossy().to_string();
 
-    create_link_internal(&sibling_text, &original_text, &LinkType::Hardlink, false)?;
+    create_link_internal(&sibling_text, &original_text, &LinkType::Hardlink, false).await?;
 
     let scanned = scan_path_with_walkdir_for_tests(root.to_string_lossy().as_ref())?;
     let hardlink_entry = scanned
@@ -249,7 +295,7 @@ async fn hardlink_scan_validate_delete_cycle() -> Result<(), Box<dyn std::error:
         original.clone()
     };
 
-    delete_link_internal(&hardlink_entry.path)?;
+    delete_link_internal(&hardlink_entry.path).await?;
     assert!(
         !deleted_path.exists(),
         "Deleted hardlink path should be removed after delete"
@@ -278,6 +324,7 @@ async fn broken_junction_keeps_type_and_reports_broken() -> Result<(), Box<dyn s
     let target_text = target_dir.to_string_lossy().to_string();
 
     create_link_internal(&link_text, &target_text, &LinkType::Junction, false)
+        .await
         .map_err(|e| format!("create junction failed: {e}"))?;
 
     fs::remove_dir_all(&target_dir)?;
@@ -301,7 +348,7 @@ async fn broken_junction_keeps_type_and_reports_broken() -> Result<(), Box<dyn s
         validated[0].status
     );
 
-    if let Err(error) = delete_link_internal(&link_text) {
+    if let Err(error) = delete_link_internal(&link_text).await {
         if is_access_denied(&error) {
             eprintln!("Skipping broken junction delete assertion due access restrictions: {error}");
             remove_if_exists(&root);